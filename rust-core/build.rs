@@ -1,5 +1,221 @@
 extern crate napi_build;
 
+use serde_json::{Map, Value};
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
 fn main() {
     napi_build::setup();
+    compile_framework_data();
+}
+
+/// Rust expression for one entry of a data file, given the entry's location for error messages
+type EntryCode = fn(&Value, &str) -> String;
+
+/// Data files per framework: table name suffix, entry type and entry code
+const DATA_FILES: &[(&str, &str, &str, EntryCode)] = &[
+    ("tags.json", "TAGS", "StaticTag", tag_code),
+    ("js_tags.json", "JS_TAGS", "StaticTag", tag_code),
+    ("docs.json", "DOCS", "StaticDoc", doc_code),
+    ("global_attributes.json", "GLOBAL_ATTRIBUTES", "StaticAttr", attr_code),
+];
+
+/// Compile `data/<framework>/*.json` into perfect-hash maps named after the framework and file
+/// (`data/element-ui/tags.json` becomes `ELEMENT_UI_TAGS`), included by `framework_data::compiled`.
+fn compile_framework_data() {
+    println!("cargo:rerun-if-changed=data");
+    let mut frameworks: Vec<_> = fs::read_dir("data")
+        .expect("read data directory")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    frameworks.sort();
+
+    let mut out = String::new();
+    for dir in frameworks {
+        let prefix = dir.file_name().unwrap().to_str().unwrap().replace('-', "_").to_uppercase();
+        for (file, suffix, value_type, entry_code) in DATA_FILES {
+            let path = dir.join(file);
+            if !path.exists() {
+                continue;
+            }
+            println!("cargo:rerun-if-changed={}", path.display());
+            let content = fs::read_to_string(&path).unwrap();
+            let entries: Map<String, Value> =
+                serde_json::from_str(&content).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+            let mut map = phf_codegen::Map::new();
+            for (key, value) in &entries {
+                map.entry(key.as_str(), &entry_code(value, &format!("{}: {}", path.display(), key)));
+            }
+            writeln!(out, "pub static {}_{}: phf::Map<&'static str, {}> = {};", prefix, suffix, value_type, map.build()).unwrap();
+        }
+    }
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("framework_data.rs");
+    fs::write(dest, out).unwrap();
+}
+
+/// The fields of the object at `at`. Fails the build on a missing `required` field and on any
+/// field that is neither required nor `optional`, so a misspelled key is not silently dropped.
+fn fields<'a>(value: &'a Value, at: &str, required: &[&str], optional: &[&str]) -> &'a Map<String, Value> {
+    let Some(object) = value.as_object() else {
+        panic!("{}: expected an object, found {}", at, value);
+    };
+    for key in object.keys() {
+        if !required.contains(&key.as_str()) && !optional.contains(&key.as_str()) {
+            panic!("{}: unknown field `{}`, expected one of {:?}", at, key, [required, optional].concat());
+        }
+    }
+    for key in required {
+        if !object.contains_key(*key) {
+            panic!("{}: missing field `{}`", at, key);
+        }
+    }
+    object
+}
+
+/// A tag is either its snippet or `{ "snippet", "since", "deprecated" }`
+fn tag_code(value: &Value, at: &str) -> String {
+    if let Value::String(snippet) = value {
+        return format!("StaticTag {{ snippet: {:?}, since: None, deprecated: None }}", snippet);
+    }
+    let tag = fields(value, at, &["snippet"], &["since", "deprecated"]);
+    format!(
+        "StaticTag {{ snippet: {}, since: {}, deprecated: {} }}",
+        str_code(tag, "snippet", at),
+        opt_code(tag, "since", at),
+        opt_code(tag, "deprecated", at),
+    )
+}
+
+fn attr_code(value: &Value, at: &str) -> String {
+    let attr = fields(value, at, &["type", "description"], &["values", "since", "deprecated"]);
+    format!(
+        "StaticAttr {{ attr_type: {}, description: {}, values: {}, since: {}, deprecated: {} }}",
+        str_code(attr, "type", at),
+        text_code(attr, "description", at),
+        list_code(attr, "values", at, string_code),
+        opt_code(attr, "since", at),
+        opt_code(attr, "deprecated", at),
+    )
+}
+
+fn doc_code(value: &Value, at: &str) -> String {
+    let doc = fields(value, at, &[], &["url", "description", "children", "props", "events", "slots", "methods"]);
+    format!(
+        "StaticDoc {{ url: {}, description: {}, children: {}, props: {}, events: {}, slots: {}, methods: {} }}",
+        opt_code(doc, "url", at),
+        text_code(doc, "description", at),
+        list_code(doc, "children", at, string_code),
+        list_code(doc, "props", at, prop_code),
+        list_code(doc, "events", at, event_code),
+        list_code(doc, "slots", at, slot_code),
+        list_code(doc, "methods", at, method_code),
+    )
+}
+
+/// Props take their type from the library docs, which leave it out for some props
+fn prop_code(value: &Value, at: &str) -> String {
+    let prop = fields(value, at, &["name"], &["description", "type", "values", "default", "required", "since", "deprecated"]);
+    let required = match prop.get("required") {
+        None => false,
+        Some(value) => value.as_bool().unwrap_or_else(|| panic!("{}.required: expected a boolean, found {}", at, value)),
+    };
+    format!(
+        "StaticProp {{ name: {}, description: {}, prop_type: {}, values: {}, default: {}, required: {}, since: {}, deprecated: {} }}",
+        str_code(prop, "name", at),
+        text_code(prop, "description", at),
+        str_code(prop, "type", at),
+        list_code(prop, "values", at, string_code),
+        opt_code(prop, "default", at),
+        required,
+        opt_code(prop, "since", at),
+        opt_code(prop, "deprecated", at),
+    )
+}
+
+fn event_code(value: &Value, at: &str) -> String {
+    let event = fields(value, at, &["name", "description"], &["params", "since", "deprecated"]);
+    format!(
+        "StaticEvent {{ name: {}, description: {}, params: {}, since: {}, deprecated: {} }}",
+        str_code(event, "name", at),
+        text_code(event, "description", at),
+        list_code(event, "params", at, typed_name_code),
+        opt_code(event, "since", at),
+        opt_code(event, "deprecated", at),
+    )
+}
+
+fn slot_code(value: &Value, at: &str) -> String {
+    let slot = fields(value, at, &["name", "description"], &["props"]);
+    format!(
+        "StaticSlot {{ name: {}, description: {}, props: {} }}",
+        str_code(slot, "name", at),
+        text_code(slot, "description", at),
+        list_code(slot, "props", at, typed_name_code),
+    )
+}
+
+fn method_code(value: &Value, at: &str) -> String {
+    let method = fields(value, at, &["name"], &["description", "params"]);
+    format!(
+        "StaticMethod {{ name: {}, description: {}, params: {} }}",
+        str_code(method, "name", at),
+        text_code(method, "description", at),
+        str_code(method, "params", at),
+    )
+}
+
+/// `{ "name", "type" }` as a `(name, type)` tuple
+fn typed_name_code(value: &Value, at: &str) -> String {
+    let typed = fields(value, at, &["name", "type"], &[]);
+    format!("({}, {})", str_code(typed, "name", at), str_code(typed, "type", at))
+}
+
+/// `{ "zh", "en" }` as a `(zh, en)` tuple. Either side may be left out, but not both.
+/// A missing optional description is `("", "")`.
+fn text_code(object: &Map<String, Value>, key: &str, at: &str) -> String {
+    let Some(value) = object.get(key) else {
+        return "(\"\", \"\")".to_string();
+    };
+    let at = format!("{}.{}", at, key);
+    let text = fields(value, &at, &[], &["zh", "en"]);
+    if text.is_empty() {
+        panic!("{}: expected `zh` or `en`", at);
+    }
+    format!("({}, {})", str_code(text, "zh", &at), str_code(text, "en", &at))
+}
+
+/// A string field, empty when left out
+fn str_code(object: &Map<String, Value>, key: &str, at: &str) -> String {
+    match object.get(key) {
+        None => "\"\"".to_string(),
+        Some(value) => string_code(value, &format!("{}.{}", at, key)),
+    }
+}
+
+fn opt_code(object: &Map<String, Value>, key: &str, at: &str) -> String {
+    match object.get(key) {
+        None => "None".to_string(),
+        Some(value) => format!("Some({})", string_code(value, &format!("{}.{}", at, key))),
+    }
+}
+
+fn string_code(value: &Value, at: &str) -> String {
+    match value.as_str() {
+        Some(s) => format!("{:?}", s),
+        None => panic!("{}: expected a string, found {}", at, value),
+    }
+}
+
+fn list_code(object: &Map<String, Value>, key: &str, at: &str, item_code: EntryCode) -> String {
+    let items: Vec<String> = match object.get(key) {
+        None => Vec::new(),
+        Some(Value::Array(items)) => {
+            items.iter().enumerate().map(|(i, item)| item_code(item, &format!("{}.{}[{}]", at, key, i))).collect()
+        }
+        Some(value) => panic!("{}.{}: expected an array, found {}", at, key, value),
+    };
+    format!("&[{}]", items.join(", "))
 }
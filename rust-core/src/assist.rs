@@ -1,4 +1,3 @@
-use napi::bindgen_prelude::*;
 use regex::Regex;
 use once_cell::sync::Lazy;
 
@@ -151,13 +150,12 @@ fn try_js_block(
     let start_search_line = start_l;
     let start_search_char = start_c + 1;
 
-    for line_idx in start_search_line..lines.len() {
-        let ln_chars: Vec<char> = lines[line_idx].chars().collect();
+    for (line_idx, line_text) in lines.iter().enumerate().skip(start_search_line) {
         let start = if line_idx == start_search_line { start_search_char } else { 0 };
-        for char_idx in start..ln_chars.len() {
-            if ln_chars[char_idx] == open {
+        for (char_idx, ch) in line_text.chars().enumerate().skip(start) {
+            if ch == open {
                 depth_fwd += 1;
-            } else if ln_chars[char_idx] == close {
+            } else if ch == close {
                 if depth_fwd == 0 {
                     found_end = Some((line_idx, char_idx));
                     break;
@@ -226,8 +224,7 @@ fn try_html_block(
 
     // Search forward for closing tag
     depth = 0;
-    for line in start_line..lines.len() {
-        let lt = &lines[line];
+    for (line, lt) in lines.iter().enumerate().skip(start_line) {
         let opens = open_tag_re.find_iter(lt).count() as i32;
         let closes = close_tag_re.find_iter(lt).count() as i32;
         depth += opens - closes;
@@ -348,7 +345,7 @@ pub fn compute_backspace_edit(
     // Delete spaces to previous tab stop (2-space unindent)
     let leading_spaces = line.len() - line.trim_start().len();
     if cursor_char <= leading_spaces && cursor_char > 0 {
-        let spaces_to_delete = if cursor_char % 2 == 0 { 2.min(cursor_char) } else { 1 };
+        let spaces_to_delete = if cursor_char.is_multiple_of(2) { 2.min(cursor_char) } else { 1 };
         return Some(EditOperation {
             start_line: cursor_line as u32,
             start_char: (cursor_char - spaces_to_delete) as u32,
@@ -0,0 +1,230 @@
+//! Edits importing a component into a single-file component and registering it. In
+//! `<script setup>` the import is enough; with the Options API the component is also added to the
+//! `components` option, which is created when missing.
+
+use crate::assist::EditOperation;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static RE_EXPORT_DEFAULT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\s*)export\s+default\s+(?:\w+\.extend\s*\(\s*|defineComponent\s*\(\s*)?\{").unwrap()
+});
+
+static RE_COMPONENTS: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\s*)components\s*:\s*\{").unwrap());
+
+/// Edits importing `name` from `specifier` into the document `content` and registering it.
+/// Nothing is returned for a component that is already imported. A document without a script
+/// gets a new `<script>` block.
+pub fn import_edits(content: &str, name: &str, specifier: &str, tab_size: &str) -> Vec<EditOperation> {
+    let import = format!("import {} from '{}'", name, specifier);
+    let lines: Vec<&str> = content.lines().collect();
+    let scripts: Vec<usize> = (0..lines.len()).filter(|&i| lines[i].trim_start().starts_with("<script")).collect();
+    // `<script setup>` takes the import when a component has both blocks
+    let Some(&script_line) = scripts.iter().find(|&&i| lines[i].contains("setup")).or(scripts.first()) else {
+        let text = format!("\n<script>\n{}\n\nexport default {{\n{}components: {{ {} }}\n}}\n</script>\n", import, tab_size, name);
+        return vec![insert_at(lines.len(), 0, text)];
+    };
+    let script_end = (script_line..lines.len()).find(|&i| lines[i].trim_start().starts_with("</script>")).unwrap_or(lines.len());
+    let script = &lines[script_line + 1..script_end];
+
+    if script.iter().any(|line| imports(line, name)) {
+        return Vec::new();
+    }
+
+    let mut edits = vec![import_edit(script, script_line + 1, &import)];
+    if !lines[script_line].contains("setup") {
+        edits.extend(register_edit(script, script_line + 1, name, tab_size));
+    }
+    edits
+}
+
+/// Whether `line` is a default import of `name`, `import UserList from ...`
+fn imports(line: &str, name: &str) -> bool {
+    line.trim_start()
+        .strip_prefix("import")
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .and_then(|rest| rest.trim_start().strip_prefix(name))
+        .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$'))
+}
+
+fn insert_at(line: usize, character: usize, text: String) -> EditOperation {
+    EditOperation {
+        start_line: line as u32,
+        start_char: character as u32,
+        end_line: line as u32,
+        end_char: character as u32,
+        text,
+    }
+}
+
+/// Column of the end of `line`, in UTF-16 code units as editors count them
+fn line_end(line: &str) -> usize {
+    line.encode_utf16().count()
+}
+
+/// The import goes after the last import of the script, a multi-line import included,
+/// otherwise at the top of the script followed by an empty line
+fn import_edit(script: &[&str], first_line: usize, import: &str) -> EditOperation {
+    let mut last_import = None;
+    let mut in_import = false;
+    for (i, line) in script.iter().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("import ") || in_import {
+            // `import {` continues until the line naming the module
+            in_import = !(trimmed.contains(" from ") || trimmed.starts_with("from ") || trimmed.starts_with("import '") || trimmed.starts_with("import \""));
+            last_import = Some(i);
+        }
+    }
+    match last_import {
+        Some(i) => insert_at(first_line + i + 1, 0, format!("{}\n", import)),
+        None => {
+            let blank = script.first().is_some_and(|line| line.trim().is_empty());
+            insert_at(first_line, 0, format!("{}\n{}", import, if blank { "" } else { "\n" }))
+        }
+    }
+}
+
+/// Add `name` to the `components` option of `export default {`, or create the option
+fn register_edit(script: &[&str], first_line: usize, name: &str, tab_size: &str) -> Option<EditOperation> {
+    let export_line = script.iter().position(|line| RE_EXPORT_DEFAULT.is_match(line))?;
+    let options = &script[export_line + 1..];
+
+    let Some(components_line) = options.iter().position(|line| RE_COMPONENTS.is_match(line)) else {
+        return option_edit(script, first_line, &format!("components: {{ {} }}", name), tab_size);
+    };
+    let line_index = first_line + export_line + 1 + components_line;
+    let line = options[components_line];
+    let open = line.find('{')?;
+
+    // components: { A, B } on one line
+    if let Some(close) = line[open..].find('}').map(|i| open + i) {
+        let inner = &line[open + 1..close];
+        let column = |byte: usize| line[..byte].encode_utf16().count();
+        return Some(if inner.trim().is_empty() {
+            EditOperation {
+                start_line: line_index as u32,
+                start_char: column(open + 1) as u32,
+                end_line: line_index as u32,
+                end_char: column(close) as u32,
+                text: format!(" {} ", name),
+            }
+        } else {
+            let end = open + 1 + inner.trim_end().len();
+            let comma = if inner.trim_end().ends_with(',') { " " } else { ", " };
+            insert_at(line_index, column(end), format!("{}{}", comma, name))
+        });
+    }
+
+    // components: {\n A,\n B\n}: after the last entry, with its indentation
+    let indent = &RE_COMPONENTS.captures(line)?[1];
+    let close = options[components_line + 1..].iter().position(|l| l.trim_start().starts_with('}'))? + components_line + 1;
+    let last = (components_line..close).rev().find(|&i| !options[i].trim().is_empty())?;
+    if last == components_line {
+        return Some(insert_at(line_index, line_end(line), format!("\n{}{}{}", indent, tab_size, name)));
+    }
+    let last_line = options[last];
+    let entry_indent = &last_line[..last_line.len() - last_line.trim_start().len()];
+    let text = if last_line.trim_end().ends_with(',') {
+        format!("\n{}{},", entry_indent, name)
+    } else {
+        format!(",\n{}{}", entry_indent, name)
+    };
+    Some(insert_at(first_line + export_line + 1 + last, line_end(last_line.trim_end()), text))
+}
+
+/// Add `option` as the first option of `export default {`. Lines after the first line of `option`
+/// are indented relative to it. On `export default { name: 'Home' }` the options already written
+/// move to a line of their own.
+pub(crate) fn option_edit(script: &[&str], first_line: usize, option: &str, tab_size: &str) -> Option<EditOperation> {
+    let export_line = script.iter().position(|line| RE_EXPORT_DEFAULT.is_match(line))?;
+    let line = script[export_line];
+    let caps = RE_EXPORT_DEFAULT.captures(line)?;
+    let indent = format!("{}{}", &caps[1], tab_size);
+    let option = option.lines().map(|l| format!("{}{}", indent, l)).collect::<Vec<_>>().join("\n");
+
+    let open = caps.get(0)?.end();
+    let rest = &line[open..];
+    if rest.trim().is_empty() {
+        return Some(insert_at(first_line + export_line + 1, 0, format!("{},\n", option)));
+    }
+    let column = line[..open].encode_utf16().count();
+    let spaces = rest.len() - rest.trim_start().len();
+    // `export default {}` closes on the next line, other options stay at the option indentation
+    let next = if rest.trim_start().starts_with('}') { &caps[1] } else { indent.as_str() };
+    Some(EditOperation {
+        start_line: (first_line + export_line) as u32,
+        start_char: column as u32,
+        end_line: (first_line + export_line) as u32,
+        end_char: (column + spaces) as u32,
+        text: format!("\n{},\n{}", option, next),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Apply edits that do not overlap, from the last to the first
+    fn apply(content: &str, mut edits: Vec<EditOperation>) -> String {
+        let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+        lines.push(String::new());
+        edits.sort_by_key(|e| (e.start_line, e.start_char));
+        for edit in edits.iter().rev() {
+            let (line, start, end) = (edit.start_line as usize, edit.start_char as usize, edit.end_char as usize);
+            let old = &lines[line];
+            let replaced = format!("{}{}{}", &old[..start], edit.text, &old[end..]);
+            let new_lines: Vec<String> = replaced.split('\n').map(str::to_string).collect();
+            lines.splice(line..=line, new_lines);
+        }
+        lines.join("\n")
+    }
+
+    #[test]
+    fn test_import_edits_options_api() {
+        let content = "<template>\n  <user-list />\n</template>\n\n<script>\nimport {\n  mapState\n} from 'vuex'\nimport Header from './Header.vue'\n\nexport default {\n  components: {\n    Header\n  },\n  data() {}\n}\n</script>";
+        let result = apply(content, import_edits(content, "UserList", "@/components/UserList.vue", "  "));
+        assert!(result.contains("import Header from './Header.vue'\nimport UserList from '@/components/UserList.vue'\n\nexport default"));
+        assert!(result.contains("  components: {\n    Header,\n    UserList\n  },"));
+        // Imported once
+        assert!(import_edits(&result, "UserList", "@/components/UserList.vue", "  ").is_empty());
+
+        let one_line = "<script>\nexport default {\n  components: { Header },\n}\n</script>";
+        let result = apply(one_line, import_edits(one_line, "UserList", "./UserList.vue", "  "));
+        assert_eq!(result, "<script>\nimport UserList from './UserList.vue'\n\nexport default {\n  components: { Header, UserList },\n}\n</script>\n");
+        let empty = "<script>\nexport default {\n  components: {},\n}\n</script>";
+        assert!(apply(empty, import_edits(empty, "UserList", "./UserList.vue", "  ")).contains("  components: { UserList },"));
+    }
+
+    #[test]
+    fn test_import_edits_create_components() {
+        let content = "<script>\nimport Vue from 'vue'\nexport default Vue.extend({\n\tdata() {}\n})\n</script>";
+        let result = apply(content, import_edits(content, "UserList", "./UserList.vue", "\t"));
+        assert_eq!(result, "<script>\nimport Vue from 'vue'\nimport UserList from './UserList.vue'\nexport default Vue.extend({\n\tcomponents: { UserList },\n\tdata() {}\n})\n</script>\n");
+
+        let no_script = "<template>\n  <user-list />\n</template>";
+        let result = apply(no_script, import_edits(no_script, "UserList", "./UserList.vue", "  "));
+        assert!(result.ends_with("</template>\n\n<script>\nimport UserList from './UserList.vue'\n\nexport default {\n  components: { UserList }\n}\n</script>\n"));
+    }
+
+    #[test]
+    fn test_import_edits_script_setup() {
+        let content = "<script>\nexport default { name: 'Home' }\n</script>\n<script setup lang=\"ts\">\nimport { ref } from 'vue'\nconst a = ref(0)\n</script>";
+        let edits = import_edits(content, "UserList", "./UserList.vue", "  ");
+        assert_eq!(edits.len(), 1);
+        assert_eq!((edits[0].start_line, edits[0].text.as_str()), (5, "import UserList from './UserList.vue'\n"));
+    }
+
+    #[test]
+    fn test_import_edits_one_line_export() {
+        let content = "<script>\nexport default { name: 'Home' }\n</script>";
+        let result = apply(content, import_edits(content, "UserList", "./UserList.vue", "  "));
+        assert_eq!(result, "<script>\nimport UserList from './UserList.vue'\n\nexport default {\n  components: { UserList },\n  name: 'Home' }\n</script>\n");
+
+        let empty = "<script>\nexport default {}\n</script>";
+        let result = apply(empty, import_edits(empty, "UserList", "./UserList.vue", "  "));
+        assert!(result.contains("export default {\n  components: { UserList },\n}\n</script>"));
+        // `import UserListItem` does not import `UserList`
+        let item = "<script>\nimport UserListItem from './UserListItem.vue'\nexport default {}\n</script>";
+        assert_eq!(import_edits(item, "UserList", "./UserList.vue", "  ").len(), 2);
+    }
+}
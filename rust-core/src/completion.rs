@@ -1,7 +1,12 @@
-use napi::bindgen_prelude::*;
-use crate::framework_data;
+use crate::assist::EditOperation;
+use crate::auto_import;
+use crate::completion_index::PrefixIndex;
+use crate::ranking::{self, Context, Rank, Usage};
+use crate::framework_data::{self, component_doc::EventParam, locale::Locale, FrameworkCache};
 use crate::traverse::VueFile;
+use crate::util::{self, to_kebab_case};
 use regex::Regex;
+use std::path::Path;
 use once_cell::sync::Lazy;
 
 // ---- Lazy-compiled regex statics ----
@@ -50,17 +55,94 @@ static RE_EMIT: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r#"\$emit\(\s?['"](\w*)"#).unwrap()
 });
 
+static RE_METHODS: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(\s*)methods\s*:\s*\{").unwrap()
+});
+
+/// A function or method definition: `handleChange(rows) {`, `function handleChange(`,
+/// `const handleChange =`, `handleChange: function`, `handleChange: (rows) =>`. Calls such as
+/// `this.handleChange(rows)` are not definitions.
+static RE_DEFINED_NAME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r"(?m)(?:^|[{,])\s*(?:async\s+)?(\w+)\s*\([^()]*\)\s*\{",
+        r"|\bfunction\s+(\w+)\s*\(",
+        r"|\b(?:const|let|var)\s+(\w+)\s*=",
+        r"|(?:^|[{,])\s*(\w+)\s*:\s*(?:async\s+)?(?:function\b|\()",
+    ))
+    .unwrap()
+});
+
+static RE_TAG_TOKEN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"<(/?)([\w-]+)[^<>]*?(/?)>").unwrap()
+});
+
+static RE_SLOT_NAME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:#|v-slot:)([\w-]*)$").unwrap()
+});
+
+static RE_SLOT_BINDING: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:(?:#|v-slot:)([\w-]+)|v-slot|slot-scope)\s*=\s*["']\{([^}"']*)$"#).unwrap()
+});
+
+static RE_SLOT_ATTR: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\sslot\s*=\s*["']([\w-]+)["']"#).unwrap()
+});
+
+static RE_REFS_MEMBER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\$refs(?:\.([\w$]+)|\[\s*['"]([\w$-]+)['"]\s*\])\??\.[\w$]*$"#).unwrap()
+});
+
+/// What a completion item inserts, shown as its icon
+#[napi(string_enum = "lowercase")]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub enum CompletionKind {
+    #[default]
+    Snippet,
+    Property,
+    /// Events and component methods
+    Method,
+    Value,
+    /// Components imported by name
+    Reference,
+    Folder,
+    File,
+    /// Packages under `node_modules`
+    Module,
+}
+
+/// Text a completion item replaces on one line, in UTF-16 code units as editors count them.
+/// Inserting replaces up to the cursor, replacing up to the end of the word under it.
+#[napi(object)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompletionRange {
+    pub line: u32,
+    pub start_char: u32,
+    /// The cursor
+    pub insert_end_char: u32,
+    pub replace_end_char: u32,
+}
+
 /// A completion suggestion returned to TypeScript
 #[napi(object)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct CompletionSuggestion {
     pub label: String,
     pub sort_text: String,
+    /// Snippet syntax, except for values and paths: `Value`, `Folder`, `File` and `Module`
     pub insert_text: String,
-    /// "snippet", "property", "method", "value", "reference", "folder"
-    pub kind: String,
+    pub kind: CompletionKind,
     pub detail: String,
     pub documentation: String,
+    /// `documentation` is markdown rather than plain text
+    pub markdown: bool,
+    /// Deprecated in the installed library version
+    pub deprecated: bool,
+    /// The text replaced, the word at the cursor when not given
+    pub range: Option<CompletionRange>,
+    /// Text the editor filters on instead of the label, for items matched fuzzily
+    pub filter_text: Option<String>,
+    /// Characters that accept the item when typed, and are then typed after it
+    pub commit_characters: Vec<String>,
 }
 
 /// Tag match result
@@ -157,197 +239,465 @@ pub fn not_in_template(lines: &[String], current_line: usize) -> bool {
     false
 }
 
-/// Get tag completion suggestions
+/// Get tag completion suggestions starting with `prefix`, the best `max_count` of them.
+/// `prefix` may be typed in PascalCase (`ElBut` finds `el-button`) or without the library prefix
+/// (`button` finds `el-button`). Children of `parent` and the
+/// tags the project uses most rank first.
 pub fn get_tag_suggestions(
-    frameworks: &[String],
+    data: &FrameworkCache,
     tab_size: &str,
     use_vue_snippets: bool,
+    parent: Option<&str>,
+    usage: &Usage,
+    prefix: &str,
+    max_count: usize,
 ) -> Vec<CompletionSuggestion> {
-    let mut suggestions = Vec::new();
-    let mut id = 1;
+    let prefix = to_kebab_case(prefix);
+    let children = child_tags(data, parent);
+    let mut suggestions: Vec<_> = ranked_tags(&data.completions().tags, &children, usage, &prefix, max_count)
+        .into_iter()
+        .map(|(item, rank)| CompletionSuggestion {
+            sort_text: rank.sort_key(&item.label),
+            insert_text: framework_data::render_snippet(&item.insert_text, tab_size),
+            ..item.clone()
+        })
+        .collect();
 
     if use_vue_snippets {
         let snippets = framework_data::get_vue_snippets_html(tab_size);
-        for (key, snippet) in &snippets {
-            suggestions.push(CompletionSuggestion {
-                label: key.clone(),
-                sort_text: format!("0{}{}", id, key),
-                insert_text: snippet.clone(),
-                kind: "snippet".to_string(),
-                detail: "vue-helper".to_string(),
-                documentation: String::new(),
-            });
-            id += 1;
+        for (key, snippet) in snippets.into_iter().filter(|(key, _)| key.starts_with(prefix.as_str())) {
+            let rank = Rank::new(key, &prefix, Context::Generic, 0);
+            suggestions.push(vue_snippet(key, snippet, rank));
         }
+        suggestions.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+        suggestions.truncate(max_count);
     }
 
-    let tags = framework_data::get_tags(frameworks, tab_size);
-    for (tag, snippet) in &tags {
-        suggestions.push(CompletionSuggestion {
-            label: tag.clone(),
-            sort_text: format!("00{}{}", id, tag),
-            insert_text: snippet.clone(),
-            kind: "snippet".to_string(),
-            detail: "vue-helper".to_string(),
-            documentation: String::new(),
-        });
-        id += 1;
+    suggestions
+}
+
+fn vue_snippet(key: &str, snippet: String, rank: Rank) -> CompletionSuggestion {
+    CompletionSuggestion {
+        label: key.to_string(),
+        sort_text: rank.sort_key(key),
+        insert_text: snippet,
+        kind: CompletionKind::Snippet,
+        detail: "vue-helper".to_string(),
+        documentation: String::new(),
+        deprecated: false,
+        ..Default::default()
+    }
+}
+
+/// `max_count` of a completion request, unlimited when not given
+pub fn max_count(max_count: Option<u32>) -> usize {
+    max_count.map_or(usize::MAX, |max| max as usize)
+}
+
+/// Range of the tag or attribute name at `character` of `line_text`: the `[\w-]` run before the
+/// cursor, up to the cursor when inserting and to the end of the name when replacing
+pub fn word_range(line: u32, line_text: &str, character: u32) -> CompletionRange {
+    let units: Vec<u16> = line_text.encode_utf16().collect();
+    let cursor = (character as usize).min(units.len());
+    let is_word = |unit: u16| u8::try_from(unit).is_ok_and(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-');
+    let start = units[..cursor].iter().rposition(|&unit| !is_word(unit)).map_or(0, |i| i + 1);
+    let end = units[cursor..].iter().position(|&unit| !is_word(unit)).map_or(units.len(), |i| cursor + i);
+    CompletionRange {
+        line,
+        start_char: start as u32,
+        insert_end_char: cursor as u32,
+        replace_end_char: end as u32,
     }
+}
 
-    suggestions
+/// Tags usually placed directly inside `parent`, from its component doc
+fn child_tags(data: &FrameworkCache, parent: Option<&str>) -> Vec<String> {
+    parent.and_then(|parent| data.component_doc(parent)).map(|doc| doc.children).unwrap_or_default()
+}
+
+/// Variants such as `el-button:primary` rank with their tag
+fn is_child(children: &[String], tag: &str) -> bool {
+    let name = tag.split(':').next().unwrap_or(tag);
+    children.iter().any(|child| child == name)
+}
+
+/// The best `max_count` items of `index` matching `prefix`, ranked before the cut so that
+/// children of the enclosing tag and frequently used tags are kept
+fn ranked_tags<'a>(
+    index: &'a PrefixIndex,
+    children: &[String],
+    usage: &Usage,
+    prefix: &'a str,
+    max_count: usize,
+) -> Vec<(&'a CompletionSuggestion, Rank)> {
+    let mut items: Vec<_> = index
+        .matching(prefix)
+        .map(|item| {
+            let context = if is_child(children, &item.label) { Context::Child } else { Context::Specific };
+            (item, Rank::new(&item.label, prefix, context, ranking::usage_of(usage, &item.label)))
+        })
+        .collect();
+    items.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.label.cmp(&b.0.label)));
+    items.truncate(max_count);
+    items
 }
 
 /// Get JS tag completion suggestions
 pub fn get_js_tag_suggestions(
-    frameworks: &[String],
+    data: &FrameworkCache,
     tab_size: &str,
     use_vue_snippets: bool,
 ) -> Vec<CompletionSuggestion> {
     let mut suggestions = Vec::new();
-    let mut id = 1;
 
     if use_vue_snippets {
-        let snippets = framework_data::get_vue_snippets_js(tab_size);
-        for (key, snippet) in &snippets {
-            suggestions.push(CompletionSuggestion {
-                label: key.clone(),
-                sort_text: format!("0{}{}", id, key),
-                insert_text: snippet.clone(),
-                kind: "snippet".to_string(),
-                detail: "vue-helper".to_string(),
-                documentation: String::new(),
-            });
-            id += 1;
+        for (key, snippet) in framework_data::get_vue_snippets_js(tab_size) {
+            suggestions.push(vue_snippet(key, snippet, Rank::default()));
         }
     }
 
-    let js_tags = framework_data::get_js_tags(frameworks, tab_size);
-    for (tag, snippet) in &js_tags {
+    for (tag, info) in &data.completions().js_tags {
+        let snippet = info.render(tab_size);
         suggestions.push(CompletionSuggestion {
+            sort_text: Rank::new(tag, "", Context::Specific, 0).sort_key(tag),
             label: tag.clone(),
-            sort_text: format!("00{}{}", id, tag),
             insert_text: snippet.clone(),
-            kind: "snippet".to_string(),
+            kind: CompletionKind::Snippet,
             detail: "vue-helper".to_string(),
-            documentation: snippet.clone(),
+            documentation: snippet,
+            deprecated: info.deprecated.is_some(),
+            ..Default::default()
         });
-        id += 1;
     }
 
     suggestions
 }
 
-/// Get attribute suggestions for a given tag
+/// Get attribute suggestions for a given tag starting with `prefix`, the best `max_count` of them.
+/// After `@` the events are offered, otherwise the attributes. Attributes of the tag itself rank
+/// before global attributes.
 pub fn get_attr_suggestions(
     tag: &str,
-    frameworks: &[String],
-    tab_size: &str,
+    data: &FrameworkCache,
     prefix_type: &str,
+    prefix: &str,
+    max_count: usize,
 ) -> Vec<CompletionSuggestion> {
-    let mut suggestions = Vec::new();
-    let attr_type = if prefix_type.starts_with('@') { "method" } else { "attribute" };
-
-    let attributes = framework_data::get_attributes(frameworks, tab_size);
-    if let Some(tag_attrs) = attributes.get(tag) {
-        for (name, info) in tag_attrs {
-            if name == "_self" { continue; }
-            if info.attr_type == attr_type {
-                suggestions.push(CompletionSuggestion {
-                    label: name.clone(),
-                    sort_text: format!("000{}", name),
-                    insert_text: name.clone(),
-                    kind: if attr_type == "method" { "method".to_string() } else { "property".to_string() },
-                    detail: "vue-helper".to_string(),
-                    documentation: info.description.clone(),
-                });
-            }
+    let index = data.completions().attributes(tag);
+    let index = if prefix_type.starts_with('@') { &index.events } else { &index.attributes };
+    let mut items: Vec<_> = index
+        .matching(prefix)
+        .map(|item| {
+            let own = data.attribute(tag, &item.label).is_some();
+            let context = if own { Context::Specific } else { Context::Generic };
+            (item, Rank::new(&item.label, prefix, context, 0))
+        })
+        .collect();
+    items.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.label.cmp(&b.0.label)));
+    items
+        .into_iter()
+        .take(max_count)
+        .map(|(item, rank)| CompletionSuggestion { sort_text: rank.sort_key(&item.label), ..item.clone() })
+        .collect()
+}
+
+/// Attributes insert their name. Events insert a handler binding, `change="handleChange"`,
+/// and document the payload the handler receives.
+pub(crate) fn attr_suggestion(name: &str, info: &framework_data::AttrInfo, locale: Locale) -> CompletionSuggestion {
+    let is_event = info.attr_type == "method";
+    let mut documentation = info.description.get(locale).to_string();
+    if is_event && !info.params.is_empty() {
+        if !documentation.is_empty() {
+            documentation.push_str("\n\n");
         }
+        documentation.push_str(&format!("{}({})", event_handler_name(name), param_list(&info.params)));
+    }
+    CompletionSuggestion {
+        label: name.to_string(),
+        sort_text: format!("000{}", name),
+        insert_text: if is_event { format!("{}=\"{}\"", name, event_handler_name(name)) } else { name.to_string() },
+        kind: if is_event { CompletionKind::Method } else { CompletionKind::Property },
+        detail: "vue-helper".to_string(),
+        documentation,
+        deprecated: info.deprecated.is_some(),
+        // `disabled=` accepts the attribute, an event already inserts its binding
+        commit_characters: if is_event { Vec::new() } else { vec!["=".to_string()] },
+        ..Default::default()
     }
+}
 
-    // Global attributes
-    let global_attrs = framework_data::get_global_attributes(frameworks, tab_size);
-    for (name, info) in &global_attrs {
-        if info.attr_type == attr_type {
-            suggestions.push(CompletionSuggestion {
-                label: name.clone(),
-                sort_text: format!("000{}", name),
-                insert_text: name.clone(),
-                kind: if attr_type == "method" { "method".to_string() } else { "property".to_string() },
-                detail: "vue-helper".to_string(),
-                documentation: info.description.clone(),
-            });
+/// Handler name for an event: `selection-change` → `handleSelectionChange`, `update:value` → `handleUpdateValue`
+pub fn event_handler_name(event: &str) -> String {
+    let mut name = String::from("handle");
+    for part in event.split(|c: char| !c.is_ascii_alphanumeric()).filter(|p| !p.is_empty()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.push_str(chars.as_str());
         }
     }
+    name
+}
 
-    suggestions
+fn param_list(params: &[EventParam]) -> String {
+    params.iter().map(|p| p.name.as_str()).collect::<Vec<_>>().join(", ")
+}
+
+/// Edit that adds an empty handler for an event of `tag` to the document script: a function before
+/// the end of `<script setup>`, otherwise the first method of the `methods` block. The handler takes the
+/// event payload as parameters. None when the handler already exists or the script has no place for it.
+pub fn get_event_handler_edit(
+    content: &str,
+    tag: &str,
+    event: &str,
+    data: &FrameworkCache,
+    tab_size: &str,
+) -> Option<EditOperation> {
+    let params = data.attribute(tag, event).filter(|info| info.attr_type == "method").map_or(&[][..], |info| &info.params);
+    event_handler_edit(content, &event_handler_name(event), params, tab_size)
+}
+
+fn event_handler_edit(content: &str, handler: &str, params: &[EventParam], tab_size: &str) -> Option<EditOperation> {
+    let script_start = content.find("<script")?;
+    let defined = |caps: regex::Captures| caps.iter().skip(1).flatten().any(|name| name.as_str() == handler);
+    if RE_DEFINED_NAME.captures_iter(&content[script_start..]).any(defined) {
+        return None;
+    }
+
+    let args = param_list(params);
+    let lines: Vec<&str> = content.lines().collect();
+    // With both a `<script>` and a `<script setup>`, handlers go into the setup block
+    let is_script = |line: &&str| line.trim_start().starts_with("<script");
+    let script_line = lines
+        .iter()
+        .position(|l| is_script(l) && l.contains("setup"))
+        .or_else(|| lines.iter().position(is_script))?;
+    let script_end = (script_line..lines.len()).find(|&i| lines[i].trim_start().starts_with("</script>"))?;
+    let edit = |line: usize, start: usize, end: usize, text: String| EditOperation {
+        start_line: line as u32,
+        start_char: start as u32,
+        end_line: line as u32,
+        end_char: end as u32,
+        text,
+    };
+
+    if lines[script_line].contains("setup") {
+        return Some(edit(script_end, 0, 0, format!("\nfunction {}({}) {{\n{}\n}}\n", handler, args, tab_size)));
+    }
+
+    let script = &lines[script_line + 1..script_end];
+    let method = |indent: &str| format!("{indent}{handler}({args}) {{\n{indent}{tab_size}\n{indent}}},");
+    let Some(methods_line) = script.iter().position(|line| RE_METHODS.is_match(line)) else {
+        // No `methods` option yet
+        return auto_import::option_edit(script, script_line + 1, &format!("methods: {{\n{}\n}}", method(tab_size)), tab_size);
+    };
+    let line = script[methods_line];
+    let line_index = script_line + 1 + methods_line;
+    let indent = &RE_METHODS.captures(line)?[1];
+    let inner = format!("{}{}", indent, tab_size);
+    let open = RE_METHODS.find(line)?.end();
+    let rest = &line[open..];
+    let column = |byte: usize| line[..byte].encode_utf16().count();
+
+    Some(if rest.trim().is_empty() {
+        // methods: {\n ... }
+        edit(line_index + 1, 0, 0, format!("{}\n", method(&inner)))
+    } else if rest.trim_start().starts_with('}') {
+        // methods: {},
+        let close = open + rest.find('}')?;
+        edit(line_index, column(open), column(close), format!("\n{}\n{}", method(&inner), indent))
+    } else {
+        // methods: { load() {} }: the handler goes first, the other methods move to the next line
+        let spaces = rest.len() - rest.trim_start().len();
+        edit(line_index, column(open), column(open + spaces), format!("\n{}\n{}", method(&inner), inner))
+    })
 }
 
 /// Get attribute value suggestions
 pub fn get_attr_value_suggestions(
     tag: &str,
     attr: &str,
-    frameworks: &[String],
-    tab_size: &str,
+    data: &FrameworkCache,
 ) -> Vec<CompletionSuggestion> {
     let mut suggestions = Vec::new();
-    let mut values = Vec::new();
 
-    // Check global attributes first
-    let global_attrs = framework_data::get_global_attributes(frameworks, tab_size);
-    if let Some(info) = global_attrs.get(attr) {
-        values = info.values.clone();
-    }
+    // Values of the tag's own attribute, else of the global one
+    let info = data.attribute(tag, attr).or_else(|| data.global_attribute(attr));
+    let values = info.map_or(&[][..], |info| &info.values);
 
-    // Then check tag-specific
-    let attributes = framework_data::get_attributes(frameworks, tab_size);
-    if let Some(tag_attrs) = attributes.get(tag) {
-        if let Some(info) = tag_attrs.get(attr) {
-            values = info.values.clone();
-        }
-    }
-
-    for value in &values {
+    for value in values {
         suggestions.push(CompletionSuggestion {
             label: value.clone(),
             sort_text: format!("000{}", value),
             insert_text: value.clone(),
-            kind: "value".to_string(),
+            kind: CompletionKind::Value,
             detail: "vue-helper".to_string(),
             documentation: String::new(),
+            deprecated: false,
+            ..Default::default()
         });
     }
 
     suggestions
 }
 
-/// Get element tag label suggestions (tag names from attribute data)
+/// Get element tag label suggestions (tag names from attribute data) starting with `prefix`,
+/// the best `max_count` of them
 pub fn get_element_tag_label_suggestions(
-    frameworks: &[String],
-    tab_size: &str,
+    data: &FrameworkCache,
     extension_name: &str,
+    parent: Option<&str>,
+    usage: &Usage,
+    prefix: &str,
+    max_count: usize,
 ) -> Vec<CompletionSuggestion> {
-    let mut suggestions = Vec::new();
-    let attributes = framework_data::get_attributes(frameworks, tab_size);
-    let mut labels = Vec::new();
-    let mut id = 1;
-
-    for tag in attributes.keys() {
-        let label = tag.split(':').next().unwrap_or(tag);
-        if !labels.contains(&label.to_string()) {
-            labels.push(label.to_string());
-            suggestions.push(CompletionSuggestion {
-                label: label.to_string(),
-                sort_text: format!("00{}{}", id, label),
-                insert_text: format!("{}$0></{}>", label, label),
-                kind: "snippet".to_string(),
-                detail: extension_name.to_string(),
-                documentation: String::new(),
-            });
-            id += 1;
+    let prefix = to_kebab_case(prefix);
+    let children = child_tags(data, parent);
+    ranked_tags(&data.completions().labels, &children, usage, &prefix, max_count)
+        .into_iter()
+        .map(|(item, rank)| CompletionSuggestion {
+            sort_text: rank.sort_key(&item.label),
+            detail: extension_name.to_string(),
+            ..item.clone()
+        })
+        .collect()
+}
+
+/// HTML elements that never have a closing tag
+const VOID_ELEMENTS: &[&str] = &["area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track", "wbr"];
+
+/// The innermost element still open at the end of `text`, normalized to kebab-case
+pub fn find_parent_tag(text: &str) -> Option<String> {
+    let mut stack: Vec<String> = Vec::new();
+    for caps in RE_TAG_TOKEN.captures_iter(text) {
+        let name = to_kebab_case(&caps[2]);
+        if &caps[1] == "/" {
+            if let Some(idx) = stack.iter().rposition(|open| *open == name) {
+                stack.truncate(idx);
+            }
+        } else if &caps[3] != "/" && !VOID_ELEMENTS.contains(&name.as_str()) {
+            stack.push(name);
         }
     }
+    stack.pop()
+}
 
-    suggestions
+/// Slot completions inside an open tag. After `#` or `v-slot:` the named slots of the component are
+/// offered; inside a slot binding (`#default="{ `, `v-slot="{ `, `slot-scope="{ `) the props the slot
+/// passes. On `<template>` the component is the enclosing tag, otherwise the tag itself.
+pub fn get_slot_suggestions(
+    text_before: &str,
+    data: &FrameworkCache,
+) -> Vec<CompletionSuggestion> {
+    let Some(tag) = match_pre_tag_from_text(text_before) else {
+        return Vec::new();
+    };
+    let tag_start = tag.offset as usize;
+    let tag_text = &text_before[tag_start..];
+    let component = if tag.text == "template" {
+        find_parent_tag(&text_before[..tag_start.saturating_sub(1)])
+    } else {
+        Some(to_kebab_case(&tag.text))
+    };
+    let Some(doc) = component.and_then(|c| data.component_doc(&c)) else {
+        return Vec::new();
+    };
+
+    if let Some(caps) = RE_SLOT_BINDING.captures(tag_text) {
+        let slot_name = caps
+            .get(1)
+            .map(|m| m.as_str().to_string())
+            .or_else(|| RE_SLOT_ATTR.captures(tag_text).map(|c| c[1].to_string()))
+            .unwrap_or_else(|| "default".to_string());
+        let written: Vec<&str> = caps[2].split(',').map(|p| p.split(':').next().unwrap_or("").trim()).collect();
+        let Some(slot) = doc.find_slot(&slot_name) else {
+            return Vec::new();
+        };
+        return slot
+            .props
+            .iter()
+            .filter(|prop| !written.contains(&prop.name.as_str()))
+            .map(|prop| CompletionSuggestion {
+                label: prop.name.clone(),
+                sort_text: format!("000{}", prop.name),
+                insert_text: prop.name.clone(),
+                kind: CompletionKind::Property,
+                detail: "vue-helper".to_string(),
+                documentation: prop.prop_type.clone(),
+                deprecated: false,
+                commit_characters: vec![",".to_string()],
+                ..Default::default()
+            })
+            .collect();
+    }
+
+    if RE_SLOT_NAME.is_match(tag_text) {
+        return doc
+            .slots
+            .iter()
+            .map(|slot| {
+                let props: Vec<&str> = slot.props.iter().map(|p| p.name.as_str()).collect();
+                let mut documentation = slot.description.get(data.locale).to_string();
+                if !props.is_empty() {
+                    documentation.push_str(&format!("\n\n{{ {} }}", props.join(", ")));
+                }
+                CompletionSuggestion {
+                    label: slot.name.clone(),
+                    sort_text: format!("000{}", slot.name),
+                    insert_text: if props.is_empty() { slot.name.clone() } else { format!("{}=\"{{ $1 }}\"", slot.name) },
+                    kind: CompletionKind::Property,
+                    detail: "vue-helper".to_string(),
+                    documentation: documentation.trim_start().to_string(),
+                    deprecated: false,
+                    ..Default::default()
+                }
+            })
+            .collect();
+    }
+
+    Vec::new()
+}
+
+/// Find the tag that carries `ref="name"` in a document, normalized to kebab-case
+pub fn find_ref_tag(content: &str, ref_name: &str) -> Option<String> {
+    let pattern = format!(r#"<([\w-]+)[^<>]*?\sref=["']{}["']"#, regex::escape(ref_name));
+    let re = Regex::new(&pattern).ok()?;
+    re.captures(content).map(|caps| to_kebab_case(&caps[1]))
+}
+
+/// Method completions after `this.$refs.name.` or `this.$refs['name'].`:
+/// the instance methods of the tag with `ref="name"` in the document
+pub fn get_ref_method_suggestions(
+    content: &str,
+    text_before: &str,
+    data: &FrameworkCache,
+) -> Vec<CompletionSuggestion> {
+    let Some(caps) = RE_REFS_MEMBER.captures(text_before) else {
+        return Vec::new();
+    };
+    let ref_name = caps.get(1).or_else(|| caps.get(2)).map(|m| m.as_str()).unwrap_or_default();
+    let Some(doc) = find_ref_tag(content, ref_name)
+        .and_then(|tag| data.component_doc(&tag))
+    else {
+        return Vec::new();
+    };
+
+    doc.methods
+        .iter()
+        .map(|method| {
+            let takes_args = !method.params.is_empty() && method.params != "()";
+            CompletionSuggestion {
+                label: method.name.clone(),
+                sort_text: format!("000{}", method.name),
+                insert_text: if takes_args { format!("{}($1)", method.name) } else { format!("{}()", method.name) },
+                kind: CompletionKind::Method,
+                detail: format!("{}{}", method.name, method.params),
+                documentation: method.description.get(data.locale).to_string(),
+                deprecated: false,
+                ..Default::default()
+            }
+        })
+        .collect()
 }
 
 /// Extract props from a vue file content
@@ -364,13 +714,12 @@ pub fn extract_props_from_vue(content: &str) -> Vec<CompletionSuggestion> {
                 let chars: Vec<char> = after_props[brace_start..].chars().collect();
                 let mut depth = 0;
                 let mut prop_text = String::new();
-                let mut in_top_level = false;
 
                 for ch in &chars {
                     match ch {
                         '{' => {
                             depth += 1;
-                            if depth == 1 { in_top_level = true; continue; }
+                            if depth == 1 { continue; }
                             if depth >= 2 { continue; }
                         }
                         '}' => {
@@ -390,16 +739,18 @@ pub fn extract_props_from_vue(content: &str) -> Vec<CompletionSuggestion> {
                 let mut idx = 0;
                 for cap in RE_PROP_NAME.captures_iter(&prop_text) {
                     if let Some(m) = cap.get(1) {
-                        let mut prop_name = m.as_str().to_string();
+                        let prop_name = m.as_str().to_string();
                         // Convert camelCase to kebab-case
                         let kebab = to_kebab_case(&prop_name);
                         props.push(CompletionSuggestion {
                             label: kebab.clone(),
-                            sort_text: format!("0{}", idx),
+                            sort_text: ranking::position_key(idx),
                             insert_text: format!(":{}=\"$0\"", kebab),
-                            kind: "property".to_string(),
+                            kind: CompletionKind::Property,
                             detail: String::new(),
                             documentation: String::new(),
+                            deprecated: false,
+                            ..Default::default()
                         });
                         idx += 1;
                     }
@@ -414,11 +765,13 @@ pub fn extract_props_from_vue(content: &str) -> Vec<CompletionSuggestion> {
             let emit_name = m.as_str();
             props.push(CompletionSuggestion {
                 label: emit_name.to_string(),
-                sort_text: format!("0{}", props.len() + 1),
+                sort_text: ranking::position_key(props.len()),
                 insert_text: format!("@{}=\"$0\"", emit_name),
-                kind: "method".to_string(),
+                kind: CompletionKind::Method,
                 detail: String::new(),
                 documentation: String::new(),
+                deprecated: false,
+                ..Default::default()
             });
         }
     }
@@ -426,27 +779,158 @@ pub fn extract_props_from_vue(content: &str) -> Vec<CompletionSuggestion> {
     props
 }
 
-fn to_kebab_case(s: &str) -> String {
-    let mut result = String::new();
-    for (i, c) in s.chars().enumerate() {
-        if c.is_uppercase() {
-            if i > 0 {
-                result.push('-');
+/// How import suggestions write the path of a component
+#[napi(object)]
+#[derive(Clone, Debug, Default)]
+pub struct ImportPathOptions {
+    /// Alias of `alias_path`, `@` by default
+    pub alias: Option<String>,
+    /// Directory the alias points to, relative to the project root, `src` by default
+    pub alias_path: Option<String>,
+    /// `relative`, `alias`, or `auto` (default): relative for components in the directory of the
+    /// edited file or below it, the alias for the others
+    pub style: Option<String>,
+    /// Drop the `.vue` extension, kept by default as Vite requires it
+    pub drop_vue_extension: Option<bool>,
+    /// Drop the `.js`/`.ts` extension, dropped by default
+    pub drop_script_extension: Option<bool>,
+    /// Import `components/Header` instead of `components/Header/index.vue`, off by default
+    pub drop_index: Option<bool>,
+}
+
+impl ImportPathOptions {
+    /// Specifier importing `file` into `document_path`
+    pub fn specifier(&self, file: &VueFile, document_path: &str, project_root: &str) -> String {
+        self.shorten(self.path_to(file, document_path, project_root))
+    }
+
+    /// Path of `file` from `document_path`, relative or aliased as `style` says, with its full name
+    pub fn path_to(&self, file: &VueFile, document_path: &str, project_root: &str) -> String {
+        let target = Path::new(project_root).join(&file.relative_path);
+        let relative = util::get_relative_path(document_path, &target.to_string_lossy());
+        let alias = self.alias.as_deref().unwrap_or("@");
+        let alias_path = self.alias_path.as_deref().unwrap_or("src");
+        let aliased = file
+            .relative_path
+            .strip_prefix(alias_path)
+            .and_then(|rest| rest.strip_prefix('/'))
+            .filter(|_| !alias.is_empty() && !alias_path.is_empty())
+            .map(|rest| format!("{}/{}", alias, rest));
+
+        match (self.style.as_deref(), aliased) {
+            (Some("relative"), _) | (_, None) => relative,
+            (Some("alias"), Some(aliased)) => aliased,
+            (_, Some(aliased)) => if relative.starts_with("./") { relative } else { aliased },
+        }
+    }
+
+    /// Whether imports leave out the extension `ext`
+    pub fn drops_extension(&self, ext: &str) -> bool {
+        match ext {
+            "vue" => self.drop_vue_extension.unwrap_or(false),
+            "js" | "ts" | "jsx" | "tsx" => self.drop_script_extension.unwrap_or(true),
+            _ => false,
+        }
+    }
+
+    fn shorten(&self, specifier: String) -> String {
+        let path = Path::new(&specifier);
+        let drop_extension = path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| self.drops_extension(ext));
+        if self.drop_index.unwrap_or(false) && path.file_stem().is_some_and(|stem| stem == "index") {
+            if let Some((dir, _)) = specifier.rsplit_once('/') {
+                return dir.to_string();
             }
-            result.push(c.to_lowercase().next().unwrap());
-        } else {
-            result.push(c);
+        }
+        match specifier.rsplit_once('.') {
+            Some((stem, _)) if drop_extension => stem.to_string(),
+            _ => specifier,
         }
     }
-    result
 }
 
-/// Get import suggestion items
+/// Score added to components next to the edited file. Below `ranking::EXACT_NAME`, so a name
+/// typed in full still ranks first, above every other fuzzy score.
+const SAME_DIRECTORY: u32 = 2_000;
+
+/// Tags of the project's own components matching `prefix` fuzzily, the best `max_count` of them,
+/// for the document at `document_path`. The detail is the import specifier; the edits importing the
+/// picked component come from `get_component_import_edits`.
+pub fn get_component_tag_suggestions(
+    vue_files: &[VueFile],
+    document_path: &str,
+    project_root: &str,
+    options: &ImportPathOptions,
+    usage: &Usage,
+    prefix: &str,
+    max_count: usize,
+) -> Vec<CompletionSuggestion> {
+    let kebab_prefix = ranking::words(prefix).join("-");
+    let document_file = Path::new(document_path);
+    let mut items: Vec<_> = vue_files
+        .iter()
+        .filter(|vf| vf.name != "index" && Path::new(project_root).join(&vf.relative_path) != document_file)
+        .filter(|vf| prefix.is_empty() || ranking::fuzzy_score(&vf.name, prefix).is_some())
+        .map(|vf| {
+            let tag = ranking::words(&vf.name).join("-");
+            let rank = Rank::new(&tag, &kebab_prefix, Context::Specific, ranking::usage_of(usage, &tag));
+            (vf, tag, rank)
+        })
+        .collect();
+    items.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.1.cmp(&b.1)));
+    items.truncate(max_count);
+
+    items
+        .into_iter()
+        .map(|(vf, tag, rank)| {
+            let specifier = options.specifier(vf, document_path, project_root);
+            CompletionSuggestion {
+                sort_text: rank.sort_key(&tag),
+                insert_text: format!("{}$0></{}>", tag, tag),
+                kind: CompletionKind::Snippet,
+                detail: specifier.clone(),
+                documentation: import_documentation(&component_name(&tag), &specifier),
+                markdown: true,
+                deprecated: false,
+                range: None,
+                filter_text: Some(prefix.to_string()),
+                commit_characters: Vec::new(),
+                label: tag,
+            }
+        })
+        .collect()
+}
+
+/// Edits importing the component of the tag `tag` from `specifier` into the document `content`
+/// and registering it, see `auto_import::import_edits`
+pub fn get_component_import_edits(content: &str, tag: &str, specifier: &str, tab_size: &str) -> Vec<EditOperation> {
+    auto_import::import_edits(content, &component_name(tag), specifier, tab_size)
+}
+
+/// Component name of a tag, `UserList` of `user-list`
+fn component_name(tag: &str) -> String {
+    ranking::words(tag).iter().map(|word| capitalize(word)).collect()
+}
+
+/// The import statement as a markdown code block
+fn import_documentation(name: &str, specifier: &str) -> String {
+    format!("```js\nimport {} from '{}'\n```", name, specifier)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+/// Get import suggestion items, importing from the path `options` computes for `document_path`.
+/// The typed name is matched fuzzily against the component names
+/// (`usrlst` and `UL` find `UserList`), otherwise against the file paths. Names typed in full rank
+/// first, then components in the directory of `document_path`, then the better matches.
 pub fn get_import_suggestion_items(
     search_text: &str,
     vue_files: &[VueFile],
     document_path: &str,
     project_root: &str,
+    options: &ImportPathOptions,
 ) -> Vec<CompletionSuggestion> {
     let mut suggestions = Vec::new();
     let search = search_text.trim().strip_prefix("import").unwrap_or(search_text).trim();
@@ -455,23 +939,31 @@ pub fn get_import_suggestion_items(
         return suggestions;
     }
 
+    let document_dir = Path::new(document_path).parent();
+    let search_lower = search.to_lowercase();
     for vf in vue_files {
-        if !vf.path.contains(search) && !vf.name.contains(search) {
+        let Some(mut score) = ranking::fuzzy_score(&vf.name, search)
+            .or_else(|| vf.path.to_lowercase().contains(&search_lower).then_some(0))
+        else {
             continue;
+        };
+        if document_dir.is_some() && Path::new(project_root).join(&vf.relative_path).parent() == document_dir {
+            score += SAME_DIRECTORY;
         }
 
-        let mut insert_path = vf.path.clone();
-        if insert_path.ends_with(".ts") {
-            insert_path = insert_path[..insert_path.len() - 3].to_string();
-        }
-
+        let specifier = options.specifier(vf, document_path, project_root);
         suggestions.push(CompletionSuggestion {
             label: vf.name.clone(),
-            sort_text: format!("0{}", vf.name),
-            insert_text: format!("${{1:{}}} from '{}'", vf.name, insert_path),
-            kind: "reference".to_string(),
+            sort_text: ranking::score_key(score, &vf.name),
+            insert_text: format!("${{1:{}}} from '{}'", vf.name, specifier),
+            kind: CompletionKind::Reference,
             detail: vf.name.clone(),
-            documentation: format!("import {} from {}", vf.name, vf.path),
+            documentation: import_documentation(&vf.name, &specifier),
+            markdown: true,
+            deprecated: false,
+            // Matched fuzzily here, the editor must not filter again
+            filter_text: Some(search.to_string()),
+            ..Default::default()
         });
     }
 
@@ -511,8 +1003,257 @@ mod tests {
 
     #[test]
     fn test_get_tag_suggestions() {
-        let frameworks = vec!["element-ui".to_string()];
-        let suggestions = get_tag_suggestions(&frameworks, "  ", false);
+        let data = framework_data::build_cache(&["element-ui".to_string()], Locale::ZhCn);
+        let suggestions = get_tag_suggestions(&data, "  ", false, None, &Usage::new(), "", usize::MAX);
         assert!(!suggestions.is_empty());
+        // Snippet indentation follows the editor
+        let group = suggestions.iter().find(|s| s.label == "el-checkbox-group").unwrap();
+        assert!(group.insert_text.contains("\n  <el-checkbox label"));
+        let group = get_tag_suggestions(&data, "\t", false, None, &Usage::new(), "", usize::MAX).into_iter().find(|s| s.label == "el-checkbox-group").unwrap();
+        assert!(group.insert_text.contains("\n\t<el-checkbox label"));
+
+        // Children of the enclosing tag come first
+        let text = "<el-form :model=\"form\">\n  <el-select v-model=\"value\">\n    <";
+        let parent = find_parent_tag(text);
+        assert_eq!(parent.as_deref(), Some("el-select"));
+        let mut suggestions = get_tag_suggestions(&data, "  ", false, parent.as_deref(), &Usage::new(), "", usize::MAX);
+        suggestions.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+        let first: Vec<&str> = suggestions.iter().take(2).map(|s| s.label.as_str()).collect();
+        assert_eq!(first, vec!["el-option", "el-option-group"]);
+
+        let mut labels = get_element_tag_label_suggestions(&data, "vue-helper", Some("el-form"), &Usage::new(), "", usize::MAX);
+        labels.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+        assert_eq!(labels[0].label, "el-form-item");
+
+        // Only the tags starting with the typed prefix, children kept within the limit
+        let labels = |items: Vec<CompletionSuggestion>| items.into_iter().map(|s| s.label).collect::<Vec<_>>();
+        let tables = labels(get_tag_suggestions(&data, "  ", false, None, &Usage::new(), "ElTable", usize::MAX));
+        assert!(!tables.is_empty() && tables.iter().all(|label| label.starts_with("el-table")));
+        assert_eq!(labels(get_tag_suggestions(&data, "  ", false, parent.as_deref(), &Usage::new(), "el-", 1)), vec!["el-option"]);
+        let items = get_element_tag_label_suggestions(&data, "vue-helper", None, &Usage::new(), "el-button", usize::MAX);
+        assert_eq!(labels(items), vec!["el-button"]);
+        // The name without the library prefix finds the tag too
+        assert!(labels(get_tag_suggestions(&data, "  ", false, None, &Usage::new(), "button", usize::MAX)).contains(&"el-button".to_string()));
+        let items = get_element_tag_label_suggestions(&data, "vue-helper", None, &Usage::new(), "table", usize::MAX);
+        assert_eq!(labels(items)[..2], ["el-table", "el-table-column"]);
+        let events = labels(get_attr_suggestions("el-table", &data, "@", "select", usize::MAX));
+        assert_eq!(events, vec!["select", "select-all", "selection-change"]);
+
+        // The tags the project uses most first, variants with their tag
+        let usage = Usage::from([("el-tag".to_string(), 3), ("el-table-column".to_string(), 8)]);
+        let ranked = |prefix| {
+            let mut tags = get_tag_suggestions(&data, "  ", false, None, &usage, prefix, usize::MAX);
+            tags.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+            labels(tags)
+        };
+        assert_eq!(ranked("el-ta")[..4], ["el-table-column", "el-tag", "el-tag:close", "el-tab-pane"]);
+        // Exact matches before frequently used ones
+        assert_eq!(ranked("el-table")[0], "el-table");
+    }
+
+    #[test]
+    fn test_word_range() {
+        let range = |text: &str, character| {
+            let r = word_range(3, text, character);
+            (r.start_char, r.insert_end_char, r.replace_end_char)
+        };
+        assert_eq!(range("  <el-but", 9), (3, 9, 9));
+        assert_eq!(range("  <el-butt>", 7), (3, 7, 10));
+        assert_eq!(range("<el-button @sel-change", 15), (12, 15, 22));
+        // Columns count UTF-16 code units
+        assert_eq!(range("<p title=\"😀\" cla", 17), (14, 17, 17));
+        assert_eq!(range("", 4), (0, 0, 0));
+        assert_eq!(word_range(3, "<a", 2).line, 3);
+    }
+
+    #[test]
+    fn test_import_suggestions() {
+        let file = |name: &str, dir: &str| VueFile {
+            name: name.to_string(),
+            path: format!("@/{}/{}.vue", dir, name),
+            relative_path: format!("src/{}/{}.vue", dir, name),
+        };
+        let files = vec![
+            file("UserList", "components"),
+            file("UserLogin", "views"),
+            file("UnitLabel", "views"),
+            file("Header", "user-list"),
+            file("Footer", "layout"),
+        ];
+        let ranked = |search: &str| {
+            let mut items = get_import_suggestion_items(search, &files, "/app/src/views/Home.vue", "/app", &ImportPathOptions::default());
+            items.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+            items.into_iter().map(|s| s.label).collect::<Vec<_>>()
+        };
+        assert_eq!(ranked("import usrlst"), vec!["UserList"]);
+        // The acronym matches in the current directory come first
+        assert_eq!(ranked("import UL"), vec!["UnitLabel", "UserLogin", "UserList"]);
+        // Path matches come after name matches
+        assert_eq!(ranked("import list"), vec!["UserList", "Header"]);
+        // A name typed in full beats the current directory
+        assert_eq!(ranked("import userlist")[0], "UserList");
+        assert!(ranked("import ").is_empty());
+
+        let item = &get_import_suggestion_items("import usrlst", &files, "/app/src/views/Home.vue", "/app", &ImportPathOptions::default())[0];
+        assert_eq!((item.kind, item.filter_text.as_deref(), item.markdown), (CompletionKind::Reference, Some("usrlst"), true));
+    }
+
+    #[test]
+    fn test_component_tag_suggestions() {
+        let file = |name: &str, relative_path: &str| VueFile {
+            name: name.to_string(),
+            path: String::new(),
+            relative_path: relative_path.to_string(),
+        };
+        let files = vec![
+            file("UserList", "src/components/UserList.vue"),
+            file("user-card", "src/components/user-card.vue"),
+            file("index", "src/views/Home/index.vue"),
+            file("Home", "src/views/Home/index.vue"),
+        ];
+        let document = "/app/src/views/Home/index.vue";
+        let options = ImportPathOptions::default();
+        let mut items = get_component_tag_suggestions(&files, document, "/app", &options, &Usage::new(), "", usize::MAX);
+        items.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+        // The edited component itself is not offered
+        assert_eq!(items.iter().map(|s| s.label.as_str()).collect::<Vec<_>>(), vec!["user-card", "user-list"]);
+        let list = &items[1];
+        assert_eq!(list.insert_text, "user-list$0></user-list>");
+        assert_eq!(list.detail, "@/components/UserList.vue");
+        let content = "<template>\n  <\n</template>\n<script>\nexport default {\n}\n</script>";
+        let edits = get_component_import_edits(content, &list.label, &list.detail, "  ");
+        let edits: Vec<&str> = edits.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(edits, vec!["import UserList from '@/components/UserList.vue'\n\n", "  components: { UserList },\n"]);
+
+        let usage = Usage::from([("user-list".to_string(), 4)]);
+        let items = get_component_tag_suggestions(&files, document, "/app", &options, &usage, "UsLi", 1);
+        assert_eq!(items[0].label, "user-list");
+    }
+
+    #[test]
+    fn test_import_specifier() {
+        let file = |relative_path: &str| VueFile { name: String::new(), path: String::new(), relative_path: relative_path.to_string() };
+        let document = "/app/src/views/Home.vue";
+        let specifier = |options: &ImportPathOptions, relative_path| options.specifier(&file(relative_path), document, "/app");
+
+        let auto = ImportPathOptions::default();
+        assert_eq!(specifier(&auto, "src/views/List.vue"), "./List.vue");
+        assert_eq!(specifier(&auto, "src/views/user/Form.vue"), "./user/Form.vue");
+        assert_eq!(specifier(&auto, "src/components/Foo.vue"), "@/components/Foo.vue");
+        assert_eq!(specifier(&auto, "src/utils/date.ts"), "@/utils/date");
+        // Outside the alias directory the path stays relative
+        assert_eq!(specifier(&auto, "lib/Shared.vue"), "../../lib/Shared.vue");
+
+        let relative = ImportPathOptions { style: Some("relative".to_string()), ..Default::default() };
+        assert_eq!(specifier(&relative, "src/components/Foo.vue"), "../components/Foo.vue");
+        let alias = ImportPathOptions { style: Some("alias".to_string()), alias: Some("~".to_string()), ..Default::default() };
+        assert_eq!(specifier(&alias, "src/views/List.vue"), "~/views/List.vue");
+
+        let short = ImportPathOptions { drop_vue_extension: Some(true), drop_index: Some(true), ..Default::default() };
+        assert_eq!(specifier(&short, "src/components/Foo.vue"), "@/components/Foo");
+        assert_eq!(specifier(&short, "src/components/Header/index.vue"), "@/components/Header");
+        assert_eq!(specifier(&auto, "src/components/Header/index.vue"), "@/components/Header/index.vue");
+    }
+
+    #[test]
+    fn test_ref_method_suggestions() {
+        let data = framework_data::build_cache(&["element-ui".to_string()], Locale::ZhCn);
+        let content = "<template>\n  <el-form\n    :model=\"form\"\n    ref=\"myForm\">\n  </el-form>\n  <ElTable ref='table' />\n</template>";
+        assert_eq!(find_ref_tag(content, "myForm").as_deref(), Some("el-form"));
+        assert_eq!(find_ref_tag(content, "table").as_deref(), Some("el-table"));
+        assert_eq!(find_ref_tag(content, "missing"), None);
+
+        let suggestions = get_ref_method_suggestions(content, "    this.$refs.myForm.", &data);
+        let validate = suggestions.iter().find(|s| s.label == "validate").unwrap();
+        assert_eq!(validate.insert_text, "validate($1)");
+        assert!(validate.detail.starts_with("validate(callback?"));
+        let reset = suggestions.iter().find(|s| s.label == "resetFields").unwrap();
+        assert_eq!(reset.insert_text, "resetFields()");
+
+        let suggestions = get_ref_method_suggestions(content, "this.$refs['table'].clear", &data);
+        assert!(suggestions.iter().any(|s| s.label == "clearSelection"));
+
+        assert!(get_ref_method_suggestions(content, "this.form.", &data).is_empty());
+    }
+
+    #[test]
+    fn test_event_handler() {
+        assert_eq!(event_handler_name("selection-change"), "handleSelectionChange");
+        assert_eq!(event_handler_name("update:value"), "handleUpdateValue");
+
+        let data = framework_data::build_cache(&["element-ui".to_string()], Locale::ZhCn);
+        let suggestions = get_attr_suggestions("el-table", &data, "@", "", usize::MAX);
+        let selection = suggestions.iter().find(|s| s.label == "selection-change").unwrap();
+        assert_eq!(selection.insert_text, "selection-change=\"handleSelectionChange\"");
+        assert!(selection.documentation.ends_with("handleSelectionChange(selection)"));
+
+        let options = "<template>\n  <el-table />\n</template>\n<script>\nexport default {\n  methods: {\n    load() {}\n  }\n}\n</script>";
+        let edit = get_event_handler_edit(options, "el-table", "selection-change", &data, "  ").unwrap();
+        assert_eq!((edit.start_line, edit.start_char), (6, 0));
+        assert_eq!(edit.text, "    handleSelectionChange(selection) {\n      \n    },\n");
+
+        let setup = "<script setup>\nimport { ref } from 'vue'\n</script>\n<template>\n  <el-table />\n</template>";
+        let edit = get_event_handler_edit(setup, "el-table", "row-click", &data, "  ").unwrap();
+        assert_eq!(edit.start_line, 2);
+        assert_eq!(edit.text, "\nfunction handleRowClick(row, column, event) {\n  \n}\n");
+
+        // The setup block of a component with two scripts
+        let dual = "<script>\nexport default { inheritAttrs: false }\n</script>\n<script setup>\nconst rows = ref([])\n</script>";
+        let edit = get_event_handler_edit(dual, "el-table", "row-click", &data, "  ").unwrap();
+        assert_eq!(edit.start_line, 5);
+
+        // `methods` created, on one line, or empty
+        let none = "<script>\nexport default defineComponent({\n  setup() {\n    return {}\n  }\n})\n</script>";
+        let edit = get_event_handler_edit(none, "el-table", "row-click", &data, "  ").unwrap();
+        assert_eq!((edit.start_line, edit.start_char), (2, 0));
+        assert_eq!(edit.text, "  methods: {\n    handleRowClick(row, column, event) {\n      \n    },\n  },\n");
+        let one_line = "<script>\nexport default {\n  methods: { load() {} }\n}\n</script>";
+        let edit = get_event_handler_edit(one_line, "el-table", "row-click", &data, "  ").unwrap();
+        assert_eq!((edit.start_line, edit.start_char, edit.end_char), (2, 12, 13));
+        assert_eq!(edit.text, "\n    handleRowClick(row, column, event) {\n      \n    },\n    ");
+        let empty = "<script>\nexport default {\n  methods: {},\n}\n</script>";
+        let edit = get_event_handler_edit(empty, "el-table", "row-click", &data, "  ").unwrap();
+        assert_eq!((edit.start_line, edit.start_char, edit.end_char), (2, 12, 12));
+        assert_eq!(edit.text, "\n    handleRowClick(row, column, event) {\n      \n    },\n  ");
+
+        // A call is not a definition
+        let called = options.replace("load() {}", "load() { this.handleSelectionChange([]) }");
+        assert!(get_event_handler_edit(&called, "el-table", "selection-change", &data, "  ").is_some());
+
+        // Already defined
+        let defined = options.replace("load() {}", "handleSelectionChange(rows) {}");
+        assert!(get_event_handler_edit(&defined, "el-table", "selection-change", &data, "  ").is_none());
+        let defined = setup.replace("import { ref } from 'vue'", "const handleRowClick = (row) => {}");
+        assert!(get_event_handler_edit(&defined, "el-table", "row-click", &data, "  ").is_none());
+        let defined = one_line.replace("load() {}", "handleRowClick: function () {}");
+        assert!(get_event_handler_edit(&defined, "el-table", "row-click", &data, "  ").is_none());
+    }
+
+    #[test]
+    fn test_slot_suggestions() {
+        assert_eq!(find_parent_tag("<el-table :data=\"list\">\n  <el-table-column prop=\"name\">\n  "), Some("el-table-column".to_string()));
+        assert_eq!(find_parent_tag("<el-table>\n  <el-table-column />\n  <br>\n  "), Some("el-table".to_string()));
+        assert_eq!(find_parent_tag("<div></div>"), None);
+
+        let data = framework_data::build_cache(&["element-ui".to_string()], Locale::ZhCn);
+        let column = "<el-table :data=\"list\">\n  <el-table-column label=\"Name\">\n    <template #";
+        let names = get_slot_suggestions(column, &data);
+        let default = names.iter().find(|s| s.label == "default").unwrap();
+        assert_eq!(default.insert_text, "default=\"{ $1 }\"");
+        assert!(names.iter().any(|s| s.label == "header"));
+
+        let props = get_slot_suggestions(&format!("{}default=\"{{ row, ", column), &data);
+        let labels: Vec<&str> = props.iter().map(|s| s.label.as_str()).collect();
+        assert_eq!(labels, vec!["column", "$index"]);
+
+        // Vue 2 slot-scope and v-slot on the component itself
+        let scope = "<el-table-column>\n  <template slot=\"header\" slot-scope=\"{ ";
+        let labels: Vec<String> = get_slot_suggestions(scope, &data).into_iter().map(|s| s.label).collect();
+        assert_eq!(labels, vec!["column", "$index"]);
+        let tree = "<el-tree :data=\"data\" v-slot=\"{ ";
+        let labels: Vec<String> = get_slot_suggestions(tree, &data).into_iter().map(|s| s.label).collect();
+        assert_eq!(labels, vec!["node", "data"]);
+
+        assert!(get_slot_suggestions("<el-table-column label=\"", &data).is_empty());
     }
 }
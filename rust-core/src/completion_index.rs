@@ -0,0 +1,227 @@
+//! Completion items built once per framework cache entry and kept sorted by label, so a request
+//! only walks the items starting with the typed prefix instead of rebuilding every suggestion.
+
+use crate::completion::{attr_suggestion, CompletionKind, CompletionSuggestion};
+use crate::framework_data::{AttrInfo, FrameworkCache, TagInfo};
+use std::collections::HashMap;
+
+/// Suggestions sorted by label, one per label
+#[derive(Default)]
+pub struct PrefixIndex {
+    items: Vec<CompletionSuggestion>,
+    /// Positions of the tag items sorted by the name after their library prefix, `button` of
+    /// `el-button`. Empty for attribute indexes.
+    names: Vec<usize>,
+}
+
+impl PrefixIndex {
+    /// Sort the items by label. Of several items with the same label, the first one is kept.
+    pub fn new(mut items: Vec<CompletionSuggestion>) -> Self {
+        items.sort_by(|a, b| a.label.cmp(&b.label));
+        items.dedup_by(|later, kept| later.label == kept.label);
+        PrefixIndex { items, names: Vec::new() }
+    }
+
+    /// Index of tags, also found by the name after their library prefix: `button` finds `el-button`
+    /// and `van-button`
+    pub fn tags(items: Vec<CompletionSuggestion>) -> Self {
+        let mut index = PrefixIndex::new(items);
+        let mut names: Vec<usize> = (0..index.items.len()).filter(|&i| tag_name(&index.items[i].label).is_some()).collect();
+        names.sort_by_key(|&i| tag_name(&index.items[i].label));
+        index.names = names;
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Items whose label starts with `prefix` in label order, then the tags whose name after the
+    /// library prefix starts with it
+    pub fn matching<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a CompletionSuggestion> + 'a {
+        let start = self.items.partition_point(|item| item.label.as_str() < prefix);
+        let labels = self.items[start..].iter().take_while(move |item| item.label.starts_with(prefix));
+
+        let name = |i: &usize| tag_name(&self.items[*i].label).unwrap_or_default();
+        let names = if prefix.is_empty() { &[][..] } else { &self.names[self.names.partition_point(|i| name(i) < prefix)..] };
+        let named = names
+            .iter()
+            .take_while(move |i| name(i).starts_with(prefix))
+            .map(|&i| &self.items[i])
+            .filter(move |item| !item.label.starts_with(prefix));
+        labels.chain(named)
+    }
+}
+
+/// Name of a tag after its library prefix, `button` of `el-button` and `van-button`
+fn tag_name(label: &str) -> Option<&str> {
+    label.split_once('-').map(|(_, name)| name).filter(|name| !name.is_empty())
+}
+
+/// Attribute and event suggestions of one tag, global attributes included
+#[derive(Default)]
+pub struct AttrIndex {
+    pub attributes: PrefixIndex,
+    pub events: PrefixIndex,
+    /// Attribute and event data of the tag itself, or of the global attributes for the global index
+    info: HashMap<String, AttrInfo>,
+}
+
+impl AttrIndex {
+    /// Suggestions of `own` and `global`; attributes of the tag win over global ones of the same name
+    fn new(own: HashMap<String, AttrInfo>, global: &HashMap<String, AttrInfo>, data: &FrameworkCache) -> Self {
+        let inherited = global.iter().filter(|(name, _)| !own.contains_key(*name));
+        let (events, attributes): (Vec<_>, Vec<_>) = own
+            .iter()
+            .chain(inherited)
+            .filter(|(name, _)| name.as_str() != "_self")
+            .map(|(name, info)| attr_suggestion(name, info, data.locale))
+            .partition(|s| s.kind == CompletionKind::Method);
+        AttrIndex { attributes: PrefixIndex::new(attributes), events: PrefixIndex::new(events), info: own }
+    }
+}
+
+pub struct CompletionIndex {
+    /// Framework tags, their snippets still indented with tabs
+    pub tags: PrefixIndex,
+    /// Tag names from the attribute data, `el-button:primary` folded into `el-button`
+    pub labels: PrefixIndex,
+    attributes: HashMap<String, AttrIndex>,
+    global: AttrIndex,
+    /// Script completions, their snippets still indented with tabs
+    pub js_tags: HashMap<String, TagInfo>,
+}
+
+impl CompletionIndex {
+    pub fn build(data: &FrameworkCache) -> Self {
+        let tags = data
+            .tags()
+            .into_iter()
+            .map(|(tag, info)| CompletionSuggestion {
+                sort_text: format!("00{}", tag),
+                label: tag,
+                insert_text: info.snippet,
+                kind: CompletionKind::Snippet,
+                detail: "vue-helper".to_string(),
+                documentation: String::new(),
+                deprecated: info.deprecated.is_some(),
+                ..Default::default()
+            })
+            .collect();
+
+        // Sorted first so that `el-button` keeps its own description over `el-button:primary`
+        let all_attributes = data.all_attributes();
+        let global_attributes = data.global_attributes();
+        let mut attributes: Vec<_> = all_attributes.iter().collect();
+        attributes.sort_by(|a, b| a.0.cmp(b.0));
+        let labels = attributes
+            .iter()
+            .map(|(tag, tag_attrs)| {
+                let label = tag.split(':').next().unwrap_or(tag);
+                CompletionSuggestion {
+                    label: label.to_string(),
+                    sort_text: format!("00{}", label),
+                    insert_text: format!("{}$0></{}>", label, label),
+                    kind: CompletionKind::Snippet,
+                    detail: String::new(),
+                    documentation: tag_attrs.get("_self").map(|info| info.description.get(data.locale).to_string()).unwrap_or_default(),
+                    deprecated: false,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        let tag_attributes = all_attributes
+            .into_iter()
+            .map(|(tag, tag_attrs)| (tag, AttrIndex::new(tag_attrs, &global_attributes, data)))
+            .collect();
+
+        CompletionIndex {
+            tags: PrefixIndex::tags(tags),
+            labels: PrefixIndex::tags(labels),
+            attributes: tag_attributes,
+            global: AttrIndex::new(global_attributes, &HashMap::new(), data),
+            js_tags: data.js_tags(),
+        }
+    }
+
+    /// Attribute and event suggestions of `tag`, only the global ones for unknown tags
+    pub fn attributes(&self, tag: &str) -> &AttrIndex {
+        self.attributes.get(tag).unwrap_or(&self.global)
+    }
+
+    /// Attributes and events of `tag` itself, None for tags without a component doc
+    pub fn tag_attributes(&self, tag: &str) -> Option<&HashMap<String, AttrInfo>> {
+        self.attributes.get(tag).map(|index| &index.info)
+    }
+
+    /// Attribute or event `name` of `tag` itself
+    pub fn attribute(&self, tag: &str, name: &str) -> Option<&AttrInfo> {
+        self.tag_attributes(tag)?.get(name)
+    }
+
+    /// Attribute `name` valid on any tag
+    pub fn global_attribute(&self, name: &str) -> Option<&AttrInfo> {
+        self.global.info.get(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggestion(label: &str, detail: &str) -> CompletionSuggestion {
+        CompletionSuggestion {
+            label: label.to_string(),
+            sort_text: label.to_string(),
+            insert_text: label.to_string(),
+            kind: CompletionKind::Snippet,
+            detail: detail.to_string(),
+            documentation: String::new(),
+            deprecated: false,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_prefix_index() {
+        let index = PrefixIndex::new(
+            ["el-table", "el-button", "el-table-column", "el-button", "van-button", "el-tab-pane"]
+                .iter()
+                .enumerate()
+                .map(|(i, label)| suggestion(label, &i.to_string()))
+                .collect(),
+        );
+        assert_eq!(index.len(), 5);
+        // The first of the duplicate labels is kept
+        assert_eq!(index.matching("el-button").next().unwrap().detail, "1");
+
+        let labels = |prefix| index.matching(prefix).map(|s| s.label.as_str()).collect::<Vec<_>>();
+        assert_eq!(labels("el-tab"), vec!["el-tab-pane", "el-table", "el-table-column"]);
+        assert_eq!(labels("el-table"), vec!["el-table", "el-table-column"]);
+        assert_eq!(labels("").len(), 5);
+        assert!(labels("x").is_empty());
+        // Only tag indexes are searched by name
+        assert!(labels("button").is_empty());
+    }
+
+    #[test]
+    fn test_tag_names() {
+        let index = PrefixIndex::tags(
+            ["el-table", "el-button", "van-button", "el-table-column", "button-group", "x"]
+                .iter()
+                .map(|label| suggestion(label, ""))
+                .collect(),
+        );
+        let labels = |prefix| index.matching(prefix).map(|s| s.label.as_str()).collect::<Vec<_>>();
+        // Labels starting with the prefix come first, each item once
+        assert_eq!(labels("button"), vec!["button-group", "el-button", "van-button"]);
+        assert_eq!(labels("table"), vec!["el-table", "el-table-column"]);
+        assert_eq!(labels("el-tab"), vec!["el-table", "el-table-column"]);
+        assert_eq!(labels("").len(), 6);
+    }
+}
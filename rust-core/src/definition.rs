@@ -1,4 +1,3 @@
-use napi::bindgen_prelude::*;
 use regex::Regex;
 use once_cell::sync::Lazy;
 use std::path::Path;
@@ -235,10 +234,8 @@ pub fn find_in_file(
             }
 
             // data return detection
-            if attr == "data" {
-                if RE_DATA_RETURN.is_match(line_text) {
-                    brace_left_count = 2;
-                }
+            if attr == "data" && RE_DATA_RETURN.is_match(line_text) {
+                brace_left_count = 2;
             }
         }
 
@@ -0,0 +1,184 @@
+//! Framework data compiled from `data/<framework>/*.json` by `build.rs` into perfect-hash tables.
+//! Snippets indent with `'\t'`, replaced with the editor indentation by `TagInfo::render`.
+//! Providers serve lookups from these tables, converting one entry at a time.
+
+use super::component_doc::{ComponentDoc, EventDoc, EventParam, MethodDoc, PropDoc, SlotDoc, SlotProp};
+use super::locale::Text;
+use super::{AttrInfo, TagInfo};
+
+/// `("中文", "English")`, either side may be empty
+pub type StaticText = (&'static str, &'static str);
+
+pub struct StaticTag {
+    pub snippet: &'static str,
+    pub since: Option<&'static str>,
+    pub deprecated: Option<&'static str>,
+}
+
+pub struct StaticAttr {
+    pub attr_type: &'static str,
+    pub description: StaticText,
+    pub values: &'static [&'static str],
+    pub since: Option<&'static str>,
+    pub deprecated: Option<&'static str>,
+}
+
+pub struct StaticDoc {
+    pub url: Option<&'static str>,
+    pub description: StaticText,
+    pub children: &'static [&'static str],
+    pub props: &'static [StaticProp],
+    pub events: &'static [StaticEvent],
+    pub slots: &'static [StaticSlot],
+    pub methods: &'static [StaticMethod],
+}
+
+pub struct StaticProp {
+    pub name: &'static str,
+    pub description: StaticText,
+    pub prop_type: &'static str,
+    pub values: &'static [&'static str],
+    pub default: Option<&'static str>,
+    pub required: bool,
+    pub since: Option<&'static str>,
+    pub deprecated: Option<&'static str>,
+}
+
+pub struct StaticEvent {
+    pub name: &'static str,
+    pub description: StaticText,
+    /// `(name, type)` of each callback parameter
+    pub params: &'static [(&'static str, &'static str)],
+    pub since: Option<&'static str>,
+    pub deprecated: Option<&'static str>,
+}
+
+pub struct StaticSlot {
+    pub name: &'static str,
+    pub description: StaticText,
+    /// `(name, type)` of each scoped slot prop
+    pub props: &'static [(&'static str, &'static str)],
+}
+
+pub struct StaticMethod {
+    pub name: &'static str,
+    pub description: StaticText,
+    pub params: &'static str,
+}
+
+pub type Table<T> = phf::Map<&'static str, T>;
+
+include!(concat!(env!("OUT_DIR"), "/framework_data.rs"));
+
+fn owned(value: Option<&str>) -> Option<String> {
+    value.map(str::to_string)
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+impl From<&StaticTag> for TagInfo {
+    fn from(tag: &StaticTag) -> Self {
+        TagInfo { snippet: tag.snippet.to_string(), since: owned(tag.since), deprecated: owned(tag.deprecated) }
+    }
+}
+
+impl From<&StaticAttr> for AttrInfo {
+    fn from(attr: &StaticAttr) -> Self {
+        AttrInfo {
+            attr_type: attr.attr_type.to_string(),
+            description: Text::from(attr.description),
+            values: strings(attr.values),
+            params: vec![],
+            since: owned(attr.since),
+            deprecated: owned(attr.deprecated),
+        }
+    }
+}
+
+impl From<&StaticDoc> for ComponentDoc {
+    fn from(doc: &StaticDoc) -> Self {
+        ComponentDoc {
+            framework: String::new(),
+            url: owned(doc.url),
+            description: Text::from(doc.description),
+            props: doc.props.iter().map(PropDoc::from).collect(),
+            events: doc.events.iter().map(EventDoc::from).collect(),
+            slots: doc.slots.iter().map(SlotDoc::from).collect(),
+            methods: doc.methods.iter().map(MethodDoc::from).collect(),
+            children: strings(doc.children),
+        }
+    }
+}
+
+impl From<&StaticProp> for PropDoc {
+    fn from(prop: &StaticProp) -> Self {
+        PropDoc {
+            name: prop.name.to_string(),
+            description: Text::from(prop.description),
+            prop_type: prop.prop_type.to_string(),
+            values: strings(prop.values),
+            default: owned(prop.default),
+            required: prop.required,
+            since: owned(prop.since),
+            deprecated: owned(prop.deprecated),
+        }
+    }
+}
+
+impl From<&StaticEvent> for EventDoc {
+    fn from(event: &StaticEvent) -> Self {
+        EventDoc {
+            name: event.name.to_string(),
+            description: Text::from(event.description),
+            params: event
+                .params
+                .iter()
+                .map(|(name, param_type)| EventParam { name: name.to_string(), param_type: param_type.to_string() })
+                .collect(),
+            since: owned(event.since),
+            deprecated: owned(event.deprecated),
+        }
+    }
+}
+
+impl From<&StaticSlot> for SlotDoc {
+    fn from(slot: &StaticSlot) -> Self {
+        SlotDoc {
+            name: slot.name.to_string(),
+            description: Text::from(slot.description),
+            props: slot
+                .props
+                .iter()
+                .map(|(name, prop_type)| SlotProp { name: name.to_string(), prop_type: prop_type.to_string() })
+                .collect(),
+        }
+    }
+}
+
+impl From<&StaticMethod> for MethodDoc {
+    fn from(method: &StaticMethod) -> Self {
+        MethodDoc { name: method.name.to_string(), description: Text::from(method.description), params: method.params.to_string() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compiled_tables() {
+        let button = &ELEMENT_UI_DOCS["el-button"];
+        assert_eq!(button.url, Some("http://element.eleme.io/#/zh-CN/component/button"));
+        assert!(button.props.iter().any(|p| p.name == "type" && p.description == ("类型", "button type")));
+        assert!(ELEMENT_UI_TAGS["el-backtop"].since.is_some());
+        // Snippets keep tabs until rendered
+        assert!(ELEMENT_UI_TAGS["el-checkbox-group"].snippet.contains("\n\t<el-checkbox"));
+        assert!(ELEMENT_PLUS_TAGS.contains_key("el-sub-menu"));
+        assert_eq!(UNI_APP_GLOBAL_ATTRIBUTES["tap"].attr_type, "method");
+
+        let doc = ComponentDoc::from(button);
+        assert_eq!(doc.find_prop("type").unwrap().description.en, "button type");
+    }
+}
@@ -0,0 +1,413 @@
+use super::locale::{Locale, Text};
+use super::AttrInfo;
+use std::collections::HashMap;
+
+/// Structured documentation of a component. Hover markdown is rendered from it on demand,
+/// and the completion attributes of the component are generated from the same data.
+#[derive(Clone, Debug, Default)]
+pub struct ComponentDoc {
+    /// Framework label for the document header. Filled in from the provider group when empty.
+    pub framework: String,
+    /// Online documentation URL
+    pub url: Option<String>,
+    /// Short description of the component itself
+    pub description: Text,
+    pub props: Vec<PropDoc>,
+    pub events: Vec<EventDoc>,
+    pub slots: Vec<SlotDoc>,
+    pub methods: Vec<MethodDoc>,
+    /// Tags usually placed directly inside this component, ranked first when completing in it
+    pub children: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PropDoc {
+    pub name: String,
+    pub description: Text,
+    /// Type as written in the library docs (`string`, `boolean`, `string/number`)
+    pub prop_type: String,
+    /// Accepted values, offered as attribute value completions
+    pub values: Vec<String>,
+    pub default: Option<String>,
+    pub required: bool,
+    /// Library version that introduced the prop
+    pub since: Option<String>,
+    /// Library version that deprecated the prop
+    pub deprecated: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct EventDoc {
+    pub name: String,
+    pub description: Text,
+    /// Callback parameters in order
+    pub params: Vec<EventParam>,
+    pub since: Option<String>,
+    pub deprecated: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct EventParam {
+    pub name: String,
+    pub param_type: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SlotDoc {
+    pub name: String,
+    pub description: Text,
+    /// Props a scoped slot passes to its content (`#default="{ row, $index }"`)
+    pub props: Vec<SlotProp>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SlotProp {
+    pub name: String,
+    pub prop_type: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MethodDoc {
+    pub name: String,
+    pub description: Text,
+    /// Parameter list as written in the library docs (`(callback: Function)`)
+    pub params: String,
+}
+
+/// `prop("size", ("尺寸", "size"), "string").values(&["medium", "small", "mini"])`
+pub fn prop(name: &str, description: impl Into<Text>, prop_type: &str) -> PropDoc {
+    PropDoc {
+        name: name.to_string(),
+        description: description.into(),
+        prop_type: prop_type.to_string(),
+        ..Default::default()
+    }
+}
+
+/// `event("change", "绑定值变化时触发").param("value", "string")`
+pub fn event(name: &str, description: impl Into<Text>) -> EventDoc {
+    EventDoc { name: name.to_string(), description: description.into(), ..Default::default() }
+}
+
+/// `slot("default", "自定义列的内容").prop("row", "object").prop("$index", "number")`
+pub fn slot(name: &str, description: impl Into<Text>) -> SlotDoc {
+    SlotDoc { name: name.to_string(), description: description.into(), ..Default::default() }
+}
+
+pub fn method(name: &str, description: impl Into<Text>, params: &str) -> MethodDoc {
+    MethodDoc { name: name.to_string(), description: description.into(), params: params.to_string() }
+}
+
+impl PropDoc {
+    pub fn values(mut self, values: &[&str]) -> Self {
+        self.values = values.iter().map(|v| v.to_string()).collect();
+        self
+    }
+
+    pub fn default(mut self, value: &str) -> Self {
+        self.default = Some(value.to_string());
+        self
+    }
+
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn since(mut self, version: &str) -> Self {
+        self.since = Some(version.to_string());
+        self
+    }
+
+    pub fn deprecated(mut self, version: &str) -> Self {
+        self.deprecated = Some(version.to_string());
+        self
+    }
+}
+
+impl EventDoc {
+    pub fn param(mut self, name: &str, param_type: &str) -> Self {
+        self.params.push(EventParam { name: name.to_string(), param_type: param_type.to_string() });
+        self
+    }
+
+    pub fn since(mut self, version: &str) -> Self {
+        self.since = Some(version.to_string());
+        self
+    }
+
+    pub fn deprecated(mut self, version: &str) -> Self {
+        self.deprecated = Some(version.to_string());
+        self
+    }
+}
+
+impl SlotDoc {
+    pub fn prop(mut self, name: &str, prop_type: &str) -> Self {
+        self.props.push(SlotProp { name: name.to_string(), prop_type: prop_type.to_string() });
+        self
+    }
+}
+
+impl ComponentDoc {
+    pub fn new(url: impl Into<String>) -> Self {
+        ComponentDoc { url: Some(url.into()), ..Default::default() }
+    }
+
+    /// A component without online documentation
+    pub fn without_url() -> Self {
+        ComponentDoc::default()
+    }
+
+    pub fn description(mut self, description: impl Into<Text>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn children(mut self, tags: &[&str]) -> Self {
+        self.children = tags.iter().map(|t| t.to_string()).collect();
+        self
+    }
+
+    pub fn prop(mut self, prop: PropDoc) -> Self {
+        self.props.push(prop);
+        self
+    }
+
+    pub fn event(mut self, event: EventDoc) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    pub fn slot(mut self, slot: SlotDoc) -> Self {
+        self.slots.push(slot);
+        self
+    }
+
+    pub fn method(mut self, method: MethodDoc) -> Self {
+        self.methods.push(method);
+        self
+    }
+
+    pub fn find_prop(&self, name: &str) -> Option<&PropDoc> {
+        self.props.iter().find(|p| p.name == name)
+    }
+
+    pub fn find_event(&self, name: &str) -> Option<&EventDoc> {
+        self.events.iter().find(|e| e.name == name)
+    }
+
+    pub fn find_slot(&self, name: &str) -> Option<&SlotDoc> {
+        self.slots.iter().find(|s| s.name == name)
+    }
+
+    /// Insert or replace a prop by name
+    pub fn set_prop(&mut self, prop: PropDoc) {
+        match self.props.iter_mut().find(|p| p.name == prop.name) {
+            Some(existing) => *existing = prop,
+            None => self.props.push(prop),
+        }
+    }
+
+    /// Insert or replace an event by name
+    pub fn set_event(&mut self, event: EventDoc) {
+        match self.events.iter_mut().find(|e| e.name == event.name) {
+            Some(existing) => *existing = event,
+            None => self.events.push(event),
+        }
+    }
+
+    /// Completion attributes: props as `attribute`, events as `method`, and the component
+    /// description under `_self`. A prop and an event with the same name keep the prop.
+    pub fn attributes(&self) -> HashMap<String, AttrInfo> {
+        let mut attrs = HashMap::with_capacity(self.props.len() + self.events.len() + 1);
+        for event in &self.events {
+            attrs.insert(event.name.clone(), AttrInfo {
+                attr_type: "method".to_string(),
+                description: event.description.clone(),
+                values: vec![],
+                params: event.params.clone(),
+                since: event.since.clone(),
+                deprecated: event.deprecated.clone(),
+            });
+        }
+        for prop in &self.props {
+            let description = if prop.description.is_empty() { Text::from(prop.prop_type.as_str()) } else { prop.description.clone() };
+            attrs.insert(prop.name.clone(), AttrInfo {
+                attr_type: "attribute".to_string(),
+                description,
+                values: prop.values.clone(),
+                params: vec![],
+                since: prop.since.clone(),
+                deprecated: prop.deprecated.clone(),
+            });
+        }
+        if !self.description.is_empty() {
+            attrs.insert("_self".to_string(), AttrInfo {
+                attr_type: "attribute".to_string(),
+                description: self.description.clone(),
+                values: vec![],
+                params: vec![],
+                since: None,
+                deprecated: None,
+            });
+        }
+        attrs
+    }
+
+    /// Render the hover document: a link to the online docs followed by the props, events,
+    /// slots and methods tables. Empty sections are left out.
+    pub fn to_markdown(&self, tag: &str, locale: Locale) -> String {
+        let mut doc = match &self.url {
+            Some(url) => format!("[{}：{}]({}) \n\n", self.framework, url, url),
+            None => format!("**{}：{}** \n\n", self.framework, tag),
+        };
+        if !self.description.is_empty() {
+            doc.push_str(&format!("{}\n\n", self.description.get(locale)));
+        }
+
+        if !self.props.is_empty() {
+            doc.push_str(locale.pick(
+                "| 参数 | 说明 | 类型 | 可选值 | 默认值 |\n",
+                "| Attribute | Description | Type | Accepted Values | Default |\n",
+            ));
+            doc.push_str("| :--- | :--- | :--- | :--- | :--- |\n");
+            for prop in &self.props {
+                let mut description = prop.description.get(locale).to_string();
+                if prop.required {
+                    description.push_str(locale.pick("（必填）", " (required)"));
+                }
+                doc.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    marked_name(&prop.name, prop.deprecated.is_some()),
+                    cell(&description),
+                    cell(&prop.prop_type),
+                    cell(&prop.values.join(" / ")),
+                    cell(prop.default.as_deref().unwrap_or("")),
+                ));
+            }
+            doc.push('\n');
+        }
+
+        if !self.events.is_empty() {
+            doc.push_str(locale.pick("| 事件名称 | 说明 | 回调参数 |\n", "| Event | Description | Parameters |\n"));
+            doc.push_str("| :--- | :--- | :--- |\n");
+            for event in &self.events {
+                let params: Vec<String> = event
+                    .params
+                    .iter()
+                    .map(|p| typed_name(&p.name, &p.param_type))
+                    .collect();
+                doc.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    marked_name(&event.name, event.deprecated.is_some()),
+                    cell(event.description.get(locale)),
+                    cell(&params.join(", ")),
+                ));
+            }
+            doc.push('\n');
+        }
+
+        if !self.slots.is_empty() {
+            doc.push_str(locale.pick("| 插槽名 | 说明 | 作用域参数 |\n", "| Slot | Description | Scope |\n"));
+            doc.push_str("| :--- | :--- | :--- |\n");
+            for slot in &self.slots {
+                let props: Vec<String> = slot.props.iter().map(|p| typed_name(&p.name, &p.prop_type)).collect();
+                doc.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    cell(&slot.name),
+                    cell(slot.description.get(locale)),
+                    cell(&props.join(", ")),
+                ));
+            }
+            doc.push('\n');
+        }
+
+        if !self.methods.is_empty() {
+            doc.push_str(locale.pick("| 方法名 | 说明 | 参数 |\n", "| Method | Description | Parameters |\n"));
+            doc.push_str("| :--- | :--- | :--- |\n");
+            for method in &self.methods {
+                doc.push_str(&format!("| {} | {} | {} |\n", cell(&method.name), cell(method.description.get(locale)), cell(&method.params)));
+            }
+        }
+        doc.trim_end().to_string()
+    }
+}
+
+/// `name: type`, or just the name when the type is unknown
+fn typed_name(name: &str, value_type: &str) -> String {
+    if value_type.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}: {}", name, value_type)
+    }
+}
+
+/// Deprecated names are struck through
+fn marked_name(name: &str, deprecated: bool) -> String {
+    if deprecated {
+        format!("~~{}~~", cell(name))
+    } else {
+        cell(name)
+    }
+}
+
+/// Markdown table cell: empty as `—`, pipes escaped
+fn cell(text: &str) -> String {
+    if text.is_empty() {
+        "—".to_string()
+    } else {
+        text.replace('|', "\\|")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn button_doc() -> ComponentDoc {
+        let mut doc = ComponentDoc::new("http://element.eleme.io/#/zh-CN/component/button")
+            .prop(prop("size", ("尺寸", "size"), "string").values(&["medium", "small", "mini"]))
+            .prop(prop("plain", "朴素按钮", "boolean").default("false"))
+            .event(event("click", "点击按钮时触发").param("event", "MouseEvent"))
+            .slot(slot("default", "按钮内容"))
+            .slot(slot("icon", "图标").prop("loading", "boolean"));
+        doc.framework = "element".to_string();
+        doc
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let markdown = button_doc().to_markdown("el-button", Locale::ZhCn);
+        assert!(markdown.starts_with("[element：http://element.eleme.io/#/zh-CN/component/button](http://element.eleme.io/#/zh-CN/component/button)"));
+        assert!(markdown.contains("| size | 尺寸 | string | medium / small / mini | — |"));
+        assert!(markdown.contains("| plain | 朴素按钮 | boolean | — | false |"));
+        assert!(markdown.contains("| click | 点击按钮时触发 | event: MouseEvent |"));
+        assert!(markdown.contains("| default | 按钮内容 | — |"));
+        assert!(markdown.contains("| icon | 图标 | loading: boolean |"));
+        assert!(!markdown.contains("方法名"));
+
+        // English headers and descriptions, falling back to Chinese
+        let markdown = button_doc().to_markdown("el-button", Locale::En);
+        assert!(markdown.contains("| Attribute | Description | Type | Accepted Values | Default |"));
+        assert!(markdown.contains("| size | size | string |"));
+        assert!(markdown.contains("| plain | 朴素按钮 | boolean |"));
+
+        let mut local = ComponentDoc::without_url().prop(prop("a|b", "", "").deprecated("2.0.0"));
+        local.framework = "x-ui".to_string();
+        let markdown = local.to_markdown("x-foo", Locale::ZhCn);
+        assert!(markdown.starts_with("**x-ui：x-foo**"));
+        assert!(markdown.contains("| ~~a\\|b~~ | — | — | — | — |"));
+    }
+
+    #[test]
+    fn test_generated_attributes() {
+        let attrs = button_doc().description("按钮").attributes();
+        assert_eq!(attrs["size"].attr_type, "attribute");
+        assert_eq!(attrs["size"].values, vec!["medium", "small", "mini"]);
+        assert_eq!(attrs["click"].attr_type, "method");
+        assert_eq!(attrs["click"].params[0].name, "event");
+        assert_eq!(attrs["_self"].description.get(Locale::En), "按钮");
+        assert!(!attrs.contains_key("default"));
+    }
+}
@@ -0,0 +1,355 @@
+use super::component_doc::{ComponentDoc, EventDoc, PropDoc};
+use super::provider::{self, DataTable, DocTable, FrameworkProvider, TagTable};
+use super::TagInfo;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Project-local component definitions, relative to the project root
+pub const CUSTOM_COMPONENTS_FILE: &str = ".vue-helper/components.json";
+
+/// Framework name used when the definitions file has no `name`
+pub const DEFAULT_CUSTOM_FRAMEWORK: &str = "project";
+
+/// Schema of `.vue-helper/components.json`:
+///
+/// ```json
+/// {
+///   "name": "x-ui",
+///   "components": {
+///     "x-search-form": {
+///       "snippet": "<x-search-form :model=\"$1\" @search=\"$2\">\n\t$3\n</x-search-form>",
+///       "description": "Search form with collapsible fields",
+///       "doc": "markdown shown on hover",
+///       "since": "1.2.0",
+///       "attributes": {
+///         "model": { "description": "form model", "values": [] },
+///         "size": { "type": "attribute", "description": "size", "values": ["small", "large"] }
+///       },
+///       "events": { "search": { "description": "emitted on submit" } }
+///     }
+///   },
+///   "scripts": { "x-confirm": "this.$xConfirm('$1')" }
+/// }
+/// ```
+///
+/// Tabs in snippets are replaced with the editor indentation.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct ComponentDef {
+    #[serde(default)]
+    snippet: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    doc: Option<String>,
+    #[serde(default)]
+    since: Option<String>,
+    #[serde(default)]
+    deprecated: Option<String>,
+    #[serde(default)]
+    attributes: HashMap<String, AttrDef>,
+    #[serde(default)]
+    events: HashMap<String, AttrDef>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct AttrDef {
+    #[serde(default, rename = "type")]
+    attr_type: Option<String>,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    values: Vec<String>,
+    #[serde(default)]
+    since: Option<String>,
+    #[serde(default)]
+    deprecated: Option<String>,
+}
+
+/// Result of loading the project definitions file
+#[derive(Clone, Debug, Default)]
+pub struct CustomComponentsResult {
+    /// Framework name to pass to the framework data APIs, `None` when nothing was loaded
+    pub framework: Option<String>,
+    /// Number of components loaded
+    pub components: u32,
+    /// One message per invalid entry; valid entries are still loaded
+    pub errors: Vec<String>,
+}
+
+/// Framework data declared in the project definitions file
+pub struct CustomProvider {
+    name: String,
+    tags: HashMap<String, TagInfo>,
+    js_tags: HashMap<String, TagInfo>,
+    docs: HashMap<String, ComponentDoc>,
+    documents: HashMap<String, String>,
+}
+
+impl FrameworkProvider for CustomProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tags(&self) -> TagTable<'_> {
+        DataTable::Owned(&self.tags)
+    }
+
+    fn js_tags(&self) -> TagTable<'_> {
+        DataTable::Owned(&self.js_tags)
+    }
+
+    fn component_docs(&self) -> DocTable<'_> {
+        DataTable::Owned(&self.docs)
+    }
+
+    fn documents(&self) -> Option<&HashMap<String, String>> {
+        Some(&self.documents)
+    }
+}
+
+/// Parse the definitions file. Invalid entries are skipped and reported; the provider holds the valid ones.
+/// Returns `None` only when the file as a whole is unusable.
+pub fn parse_custom_components(content: &str) -> (Option<CustomProvider>, Vec<String>) {
+    let mut errors = Vec::new();
+    let root: Value = match serde_json::from_str(content) {
+        Ok(v) => v,
+        Err(e) => {
+            errors.push(format!("{}: invalid JSON: {}", CUSTOM_COMPONENTS_FILE, e));
+            return (None, errors);
+        }
+    };
+    let Some(root) = root.as_object() else {
+        errors.push(format!("{}: expected an object at the top level", CUSTOM_COMPONENTS_FILE));
+        return (None, errors);
+    };
+
+    for key in root.keys() {
+        if !matches!(key.as_str(), "name" | "components" | "scripts") {
+            errors.push(format!("{}: unknown field `{}`, expected `name`, `components` or `scripts`", CUSTOM_COMPONENTS_FILE, key));
+        }
+    }
+
+    let name = match root.get("name") {
+        None => DEFAULT_CUSTOM_FRAMEWORK.to_string(),
+        Some(Value::String(name)) => match provider::check_name(name) {
+            Ok(()) => name.clone(),
+            Err(e) => {
+                errors.push(format!("name: {}", e));
+                return (None, errors);
+            }
+        },
+        Some(other) => {
+            errors.push(format!("name: expected a string, found {}", other));
+            return (None, errors);
+        }
+    };
+
+    let mut provider = CustomProvider {
+        name,
+        tags: HashMap::new(),
+        js_tags: HashMap::new(),
+        docs: HashMap::new(),
+        documents: HashMap::new(),
+    };
+
+    match root.get("components") {
+        None => {}
+        Some(Value::Object(components)) => {
+            for (tag, def) in components {
+                if let Err(e) = add_component(&mut provider, tag, def) {
+                    errors.push(format!("components.{}: {}", tag, e));
+                }
+            }
+        }
+        Some(_) => errors.push("components: expected an object of tag name to component definition".to_string()),
+    }
+
+    match root.get("scripts") {
+        None => {}
+        Some(Value::Object(scripts)) => {
+            for (key, snippet) in scripts {
+                match snippet.as_str() {
+                    Some(snippet) => {
+                        provider.js_tags.insert(key.clone(), snippet.into());
+                    }
+                    None => errors.push(format!("scripts.{}: expected a snippet string", key)),
+                }
+            }
+        }
+        Some(_) => errors.push("scripts: expected an object of snippet name to snippet body".to_string()),
+    }
+
+    (Some(provider), errors)
+}
+
+fn add_component(provider: &mut CustomProvider, tag: &str, def: &Value) -> Result<(), String> {
+    if tag.is_empty() || tag.contains(|c: char| c.is_whitespace() || c == '<' || c == '>' || c == '/') {
+        return Err("tag name must not be empty or contain whitespace, `<`, `>` or `/`".to_string());
+    }
+    let def: ComponentDef = serde_json::from_value(def.clone()).map_err(|e| e.to_string())?;
+
+    let mut doc = ComponentDoc::without_url().description(def.description.as_deref().unwrap_or(""));
+    for (name, attr) in def.attributes {
+        match attr.attr_type.as_deref().unwrap_or("attribute") {
+            "attribute" => doc = doc.prop(to_prop_doc(name, attr)),
+            "method" => doc = doc.event(to_event_doc(name, attr)),
+            other => return Err(format!("attributes.{}: type must be `attribute` or `method`, found `{}`", name, other)),
+        }
+    }
+    for (name, event) in def.events {
+        if event.attr_type.as_deref().is_some_and(|t| t != "method") {
+            return Err(format!("events.{}: events are always of type `method`", name));
+        }
+        doc = doc.event(to_event_doc(name, event));
+    }
+
+    let snippet = def.snippet.unwrap_or_else(|| format!("<{tag}>$1</{tag}>"));
+    provider.tags.insert(tag.to_string(), TagInfo { snippet, since: def.since, deprecated: def.deprecated });
+    provider.docs.insert(tag.to_string(), doc);
+    if let Some(markdown) = def.doc {
+        provider.documents.insert(tag.to_string(), markdown);
+    }
+    Ok(())
+}
+
+fn to_prop_doc(name: String, def: AttrDef) -> PropDoc {
+    PropDoc {
+        name,
+        description: def.description.into(),
+        values: def.values,
+        since: def.since,
+        deprecated: def.deprecated,
+        ..Default::default()
+    }
+}
+
+fn to_event_doc(name: String, def: AttrDef) -> EventDoc {
+    EventDoc {
+        name,
+        description: def.description.into(),
+        since: def.since,
+        deprecated: def.deprecated,
+        ..Default::default()
+    }
+}
+
+/// Read `.vue-helper/components.json` from the project root into a provider.
+/// A missing file is not an error and loads nothing.
+pub fn read_custom_components(project_root: &str) -> (Option<CustomProvider>, CustomComponentsResult) {
+    let path = Path::new(project_root).join(CUSTOM_COMPONENTS_FILE);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return (None, CustomComponentsResult::default()),
+        Err(e) => {
+            let errors = vec![format!("{}: {}", path.display(), e)];
+            return (None, CustomComponentsResult { errors, ..Default::default() });
+        }
+    };
+
+    let (provider, errors) = parse_custom_components(&content);
+    let Some(provider) = provider else {
+        return (None, CustomComponentsResult { errors, ..Default::default() });
+    };
+    let result = CustomComponentsResult {
+        framework: Some(provider.name.clone()),
+        components: provider.tags.len() as u32,
+        errors,
+    };
+    (Some(provider), result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework_data::locale::Locale;
+    use crate::framework_data::build_cache_with;
+    use crate::test_util::TempDir;
+    use std::sync::Arc;
+
+    const DEFINITIONS: &str = r#"{
+        "name": "x-ui-test",
+        "components": {
+            "x-search-form": {
+                "snippet": "<x-search-form :model=\"$1\">\n\t$2\n</x-search-form>",
+                "description": "search form",
+                "doc": "**x-search-form**",
+                "attributes": {
+                    "model": { "description": "form model" },
+                    "size": { "description": "size", "values": ["small", "large"] }
+                },
+                "events": { "search": { "description": "emitted on submit" } }
+            },
+            "x-crud-table": {
+                "atributes": {}
+            },
+            "x-bad-type": {
+                "attributes": { "size": { "type": "prop" } }
+            }
+        },
+        "scripts": { "x-confirm": "this.$xConfirm('$1')" }
+    }"#;
+
+    #[test]
+    fn test_parse_custom_components() {
+        let (provider, errors) = parse_custom_components(DEFINITIONS);
+        let provider = provider.unwrap();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().any(|e| e.starts_with("components.x-crud-table: unknown field `atributes`")));
+        assert!(errors.iter().any(|e| e.contains("type must be `attribute` or `method`, found `prop`")));
+
+        assert_eq!(provider.name(), "x-ui-test");
+        assert_eq!(provider.tags["x-search-form"].render("  "), "<x-search-form :model=\"$1\">\n  $2\n</x-search-form>");
+        let attrs = provider.docs["x-search-form"].attributes();
+        assert_eq!(attrs["size"].values, vec!["small", "large"]);
+        assert_eq!(attrs["search"].attr_type, "method");
+        assert!(!provider.tags().contains_key("x-crud-table"));
+        assert_eq!(provider.js_tags().get("x-confirm").unwrap().snippet, "this.$xConfirm('$1')");
+    }
+
+    #[test]
+    fn test_parse_invalid_json() {
+        let (provider, errors) = parse_custom_components("{ \"components\": ");
+        assert!(provider.is_none());
+        assert!(errors[0].contains("invalid JSON"));
+
+        let (provider, errors) = parse_custom_components(r#"{ "name": "my lib" }"#);
+        assert!(provider.is_none());
+        assert!(errors[0].starts_with("name:"));
+    }
+
+    #[test]
+    fn test_read_custom_components() {
+        let root = TempDir::new("custom");
+        fs::create_dir_all(root.join(".vue-helper")).unwrap();
+        fs::write(root.join(CUSTOM_COMPONENTS_FILE), DEFINITIONS).unwrap();
+
+        let (provider, result) = read_custom_components(&root.root());
+        assert_eq!(result.framework.as_deref(), Some("x-ui-test"));
+        assert_eq!(result.components, 1);
+        assert_eq!(result.errors.len(), 2);
+
+        // Merged alongside other frameworks
+        let providers: Vec<Arc<dyn FrameworkProvider>> = vec![Arc::new(provider.unwrap())];
+        let frameworks = vec!["element-ui".to_string(), "x-ui-test".to_string()];
+        let data = build_cache_with(&frameworks, Locale::ZhCn, &providers);
+        assert!(data.tag("x-search-form").is_some());
+        assert!(data.tag("el-button").is_some());
+        assert!(data.attribute("x-search-form", "model").is_some());
+        assert_eq!(data.document("x-search-form").unwrap(), "**x-search-form**");
+
+        // Built-in providers cannot be replaced
+        fs::write(root.join(CUSTOM_COMPONENTS_FILE), r#"{ "name": "vant", "components": { "van-button": {} } }"#).unwrap();
+        let (provider, result) = read_custom_components(&root.root());
+        assert!(provider.is_none());
+        assert_eq!(result.framework, None);
+        assert!(result.errors[0].contains("built-in"));
+
+        fs::remove_file(root.join(CUSTOM_COMPONENTS_FILE)).unwrap();
+        assert_eq!(read_custom_components(&root.root()).1.framework, None);
+    }
+}
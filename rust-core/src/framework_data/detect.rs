@@ -0,0 +1,155 @@
+use super::provider::{self, FrameworkProvider};
+use super::version::Version;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+/// package.json dependency sections, in priority order
+const DEPENDENCY_SECTIONS: [&str; 3] = ["dependencies", "devDependencies", "peerDependencies"];
+
+/// A framework found in package.json
+#[napi(object)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DetectedFramework {
+    /// Framework name used by the framework data ("element-ui", "element-plus", ...)
+    pub name: String,
+    /// npm package the framework was detected from
+    pub package: String,
+    /// Semver range declared in package.json
+    pub range: String,
+    /// Version installed in node_modules, if the package could be read
+    pub version: Option<String>,
+    /// Framework name with the resolved version (`element-ui@2.15.14`) for the framework data APIs.
+    /// Falls back to the lowest version of the declared range, or the bare name.
+    pub spec: String,
+}
+
+/// Detect the built-in frameworks from the dependency sections of package.json.
+/// Only one framework per provider group is reported; provider order breaks ties.
+/// When `project_root` is given, the installed version is read from `node_modules/<pkg>/package.json`.
+pub fn detect_frameworks(package_json_content: &str, project_root: Option<&str>) -> Vec<DetectedFramework> {
+    detect_frameworks_with(package_json_content, project_root, provider::providers())
+}
+
+/// Detect the frameworks of `providers` from package.json, in their order
+pub fn detect_frameworks_with(
+    package_json_content: &str,
+    project_root: Option<&str>,
+    providers: &[Arc<dyn FrameworkProvider>],
+) -> Vec<DetectedFramework> {
+    let manifest: Value = match serde_json::from_str(package_json_content) {
+        Ok(v) => v,
+        Err(_) => return Vec::new(),
+    };
+
+    // (provider index, section rank, package, range) for every provider package that is declared
+    let mut found: Vec<(usize, usize, String, String)> = Vec::new();
+    for (idx, p) in providers.iter().enumerate() {
+        let declared = p.packages().iter().find_map(|package| {
+            DEPENDENCY_SECTIONS.iter().enumerate().find_map(|(rank, section)| {
+                let range = manifest.get(section)?.get(package)?;
+                Some((rank, package.to_string(), range.as_str().unwrap_or("").to_string()))
+            })
+        });
+        if let Some((rank, package, range)) = declared {
+            found.push((idx, rank, package, range));
+        }
+    }
+
+    // Within a group, prefer runtime dependencies over dev/peer ones, then provider order
+    let mut result = Vec::new();
+    for (idx, rank, package, range) in &found {
+        let group = providers[*idx].group();
+        let beaten = found.iter().any(|(other, other_rank, _, _)| {
+            other != idx && providers[*other].group() == group && (other_rank, other) < (rank, idx)
+        });
+        if beaten {
+            continue;
+        }
+        let name = providers[*idx].name();
+        let version = project_root.and_then(|root| installed_version(root, package));
+        let resolved = version.as_deref().and_then(Version::parse).or_else(|| Version::from_range(range));
+        result.push(DetectedFramework {
+            name: name.to_string(),
+            package: package.clone(),
+            range: range.clone(),
+            version,
+            spec: match resolved {
+                Some(v) => format!("{}@{}", name, v),
+                None => name.to_string(),
+            },
+        });
+    }
+    result
+}
+
+/// Read the installed version of a package from node_modules
+pub fn installed_version(project_root: &str, package: &str) -> Option<String> {
+    let manifest_path = Path::new(project_root).join("node_modules").join(package).join("package.json");
+    let content = fs::read_to_string(manifest_path).ok()?;
+    let manifest: Value = serde_json::from_str(&content).ok()?;
+    let version = manifest.get("version")?.as_str()?;
+    Version::parse(version).map(|_| version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    fn names(detected: &[DetectedFramework]) -> Vec<&str> {
+        detected.iter().map(|f| f.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_detect_ignores_non_dependency_mentions() {
+        let pkg = r#"{
+            "name": "vue-element-ui-extra",
+            "description": "built on element-ui",
+            "scripts": { "theme": "element-ui-theme" },
+            "dependencies": { "vue": "^2.6.14" }
+        }"#;
+        assert!(detect_frameworks(pkg, None).is_empty());
+    }
+
+    #[test]
+    fn test_detect_range() {
+        let pkg = r#"{ "dependencies": { "element-ui": "^2.15.14", "ant-design-vue": "~1.7.8" } }"#;
+        let detected = detect_frameworks(pkg, None);
+        assert_eq!(names(&detected), vec!["element-ui", "ant-design-vue"]);
+        assert_eq!(detected[0].range, "^2.15.14");
+        assert_eq!(detected[0].version, None);
+        assert_eq!(detected[0].spec, "element-ui@2.15.14");
+        assert_eq!(detected[1].spec, "ant-design-vue@1.7.8");
+    }
+
+    #[test]
+    fn test_detect_element_priority() {
+        // Same section: element-plus wins
+        let pkg = r#"{ "dependencies": { "element-ui": "^2.15.0", "element-plus": "^2.4.0" } }"#;
+        assert_eq!(names(&detect_frameworks(pkg, None)), vec!["element-plus"]);
+
+        // Runtime dependency beats a dev dependency
+        let pkg = r#"{ "dependencies": { "element-ui": "^2.15.0" }, "devDependencies": { "element-plus": "^2.4.0" } }"#;
+        assert_eq!(names(&detect_frameworks(pkg, None)), vec!["element-ui"]);
+    }
+
+    #[test]
+    fn test_detect_invalid_json() {
+        assert!(detect_frameworks("element-ui", None).is_empty());
+    }
+
+    #[test]
+    fn test_installed_version() {
+        let root = TempDir::new("detect");
+        let pkg_dir = root.join("node_modules").join("element-ui");
+        fs::create_dir_all(&pkg_dir).unwrap();
+        fs::write(pkg_dir.join("package.json"), r#"{ "name": "element-ui", "version": "2.13.2" }"#).unwrap();
+
+        let pkg = r#"{ "dependencies": { "element-ui": "^2.13.0" } }"#;
+        let detected = detect_frameworks(pkg, Some(&root.root()));
+        assert_eq!(detected[0].version.as_deref(), Some("2.13.2"));
+        assert_eq!(detected[0].spec, "element-ui@2.13.2");
+    }
+}
@@ -0,0 +1,122 @@
+use super::compiled::{
+    ELEMENT_PLUS_DOCS, ELEMENT_PLUS_TAGS, ELEMENT_UI_DOCS, ELEMENT_UI_GLOBAL_ATTRIBUTES, ELEMENT_UI_JS_TAGS, ELEMENT_UI_TAGS,
+};
+use super::provider::{AttrTable, DataTable, DocTable, FrameworkProvider, TagTable};
+
+/// English placeholder text for the Element tag and JS snippets
+const SNIPPET_TRANSLATIONS: &[(&str, &str)] = &[
+    ("下拉菜单", "Dropdown"),
+    ("黄金糕", "Item"),
+    ("取 消", "Cancel"),
+    ("确 定", "Confirm"),
+    ("点击上传", "Click to upload"),
+    ("只能上传jpg/png文件，且不超过500kb", "jpg/png files with a size less than 500kb"),
+    ("我是标题", "Title"),
+    ("我来啦!", "Hi there!"),
+    ("这是一段内容", "This is a message"),
+    ("标题名称", "Title"),
+    ("标题", "Title"),
+    ("此操作将永久删除该文件, 是否继续?", "This will permanently delete the file. Continue?"),
+    ("提示", "Warning"),
+    ("确定", "OK"),
+    ("取消", "Cancel"),
+    ("删除成功!", "Delete completed"),
+    ("已取消删除", "Delete canceled"),
+    ("恭喜你，这是一条成功消息", "Congrats, this is a success message."),
+    ("不会自动关闭的消息", "This is a message that never automatically closes"),
+    ("这是一条成功的提示消息", "This is a success message"),
+    ("成功", "Success"),
+    ("请至少选择一个", "Please select at least one"),
+    ("请选择日期", "Please pick a date"),
+    ("长度在 3 到 5 个字符", "Length should be 3 to 5"),
+    ("请输入邮箱", "Please input email address"),
+    ("请输入", "Please input"),
+];
+
+/// Element UI (Vue 2)
+pub struct ElementUiProvider;
+
+impl FrameworkProvider for ElementUiProvider {
+    fn name(&self) -> &str {
+        "element-ui"
+    }
+
+    fn packages(&self) -> &[&str] {
+        &["element-ui"]
+    }
+
+    fn group(&self) -> &str {
+        "element"
+    }
+
+    fn tags(&self) -> TagTable<'_> {
+        DataTable::Compiled(&ELEMENT_UI_TAGS)
+    }
+
+    fn js_tags(&self) -> TagTable<'_> {
+        DataTable::Compiled(&ELEMENT_UI_JS_TAGS)
+    }
+
+    fn global_attributes(&self) -> AttrTable<'_> {
+        DataTable::Compiled(&ELEMENT_UI_GLOBAL_ATTRIBUTES)
+    }
+
+    fn component_docs(&self) -> DocTable<'_> {
+        DataTable::Compiled(&ELEMENT_UI_DOCS)
+    }
+
+    fn snippet_translations(&self) -> &[(&'static str, &'static str)] {
+        SNIPPET_TRANSLATIONS
+    }
+}
+
+/// Element Plus (Vue 3). Its tags and docs are the Element UI ones with the Element Plus API
+/// differences applied and the Element UI version markers removed; the script snippets are shared.
+pub struct ElementPlusProvider;
+
+impl FrameworkProvider for ElementPlusProvider {
+    fn name(&self) -> &str {
+        "element-plus"
+    }
+
+    fn packages(&self) -> &[&str] {
+        &["element-plus"]
+    }
+
+    fn group(&self) -> &str {
+        "element"
+    }
+
+    fn tags(&self) -> TagTable<'_> {
+        DataTable::Compiled(&ELEMENT_PLUS_TAGS)
+    }
+
+    fn js_tags(&self) -> TagTable<'_> {
+        DataTable::Compiled(&ELEMENT_UI_JS_TAGS)
+    }
+
+    fn global_attributes(&self) -> AttrTable<'_> {
+        DataTable::Compiled(&ELEMENT_UI_GLOBAL_ATTRIBUTES)
+    }
+
+    fn component_docs(&self) -> DocTable<'_> {
+        DataTable::Compiled(&ELEMENT_PLUS_DOCS)
+    }
+
+    fn snippet_translations(&self) -> &[(&'static str, &'static str)] {
+        SNIPPET_TRANSLATIONS
+    }
+}
+
+/// Ant Design Vue: detected only, no framework data yet
+pub struct AntDesignVueProvider;
+
+impl FrameworkProvider for AntDesignVueProvider {
+    fn name(&self) -> &str {
+        "ant-design-vue"
+    }
+
+    fn packages(&self) -> &[&str] {
+        &["ant-design-vue"]
+    }
+}
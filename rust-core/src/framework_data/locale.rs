@@ -0,0 +1,124 @@
+/// Documentation language
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    ZhCn,
+    En,
+}
+
+impl Locale {
+    /// Parse a VS Code display language (`zh-cn`, `zh-tw`, `en`, `en-us`). Unknown languages use English,
+    /// no language at all keeps the Chinese default.
+    pub fn parse(language: Option<&str>) -> Locale {
+        match language.map(|l| l.trim().to_ascii_lowercase()) {
+            None => Locale::default(),
+            Some(l) if l.is_empty() || l.starts_with("zh") => Locale::ZhCn,
+            Some(_) => Locale::En,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Locale::ZhCn => "zh-CN",
+            Locale::En => "en",
+        }
+    }
+
+    /// Choose between a Chinese and an English string
+    pub fn pick<'a>(self, zh: &'a str, en: &'a str) -> &'a str {
+        match self {
+            Locale::ZhCn => zh,
+            Locale::En => en,
+        }
+    }
+}
+
+/// Text in both documentation languages. Either side may be empty, and the other one is used instead.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Text {
+    pub zh: String,
+    pub en: String,
+}
+
+impl Text {
+    pub fn get(&self, locale: Locale) -> &str {
+        let (preferred, fallback) = match locale {
+            Locale::ZhCn => (&self.zh, &self.en),
+            Locale::En => (&self.en, &self.zh),
+        };
+        if preferred.is_empty() {
+            fallback
+        } else {
+            preferred
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.zh.is_empty() && self.en.is_empty()
+    }
+}
+
+/// Single-language text: Chinese when it contains CJK characters, English otherwise
+impl From<&str> for Text {
+    fn from(text: &str) -> Self {
+        Text::from(text.to_string())
+    }
+}
+
+impl From<String> for Text {
+    fn from(text: String) -> Self {
+        if text.chars().any(|c| ('\u{4e00}'..='\u{9fff}').contains(&c)) {
+            Text { zh: text, en: String::new() }
+        } else {
+            Text { zh: String::new(), en: text }
+        }
+    }
+}
+
+/// `("中文", "English")`
+impl From<(&str, &str)> for Text {
+    fn from((zh, en): (&str, &str)) -> Self {
+        Text { zh: zh.to_string(), en: en.to_string() }
+    }
+}
+
+/// Replace Chinese snippet placeholder text with its English translation, longest phrases first
+/// so that a phrase containing a shorter one is translated as a whole
+pub fn translate_snippet(snippet: &str, translations: &[(&str, &str)]) -> String {
+    let mut sorted: Vec<&(&str, &str)> = translations.iter().collect();
+    sorted.sort_by_key(|(zh, _)| std::cmp::Reverse(zh.chars().count()));
+    let mut result = snippet.to_string();
+    for (zh, en) in sorted {
+        if result.contains(zh) {
+            result = result.replace(zh, en);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_fallback() {
+        assert_eq!(Locale::parse(Some("zh-cn")), Locale::ZhCn);
+        assert_eq!(Locale::parse(Some("en-US")), Locale::En);
+        assert_eq!(Locale::parse(Some("ja")), Locale::En);
+        assert_eq!(Locale::parse(None), Locale::ZhCn);
+
+        let both = Text::from(("尺寸", "size"));
+        assert_eq!(both.get(Locale::En), "size");
+        assert_eq!(both.get(Locale::ZhCn), "尺寸");
+        let zh_only = Text::from("绑定值");
+        assert_eq!(zh_only.get(Locale::En), "绑定值");
+        let en_only = Text::from("button type");
+        assert_eq!(en_only.get(Locale::ZhCn), "button type");
+    }
+
+    #[test]
+    fn test_translate_snippet() {
+        let translations = [("提示", "Tip"), ("提示内容", "Message")];
+        assert_eq!(translate_snippet("Toast('${1:提示内容}', '提示')", &translations), "Toast('${1:Message}', 'Tip')");
+    }
+}
@@ -1,47 +1,243 @@
-pub mod attribute;
-pub mod tag;
-pub mod js_tag;
-pub mod document;
-pub mod global_attribute;
+pub mod component_doc;
+pub mod compiled;
 pub mod snippets_html;
 pub mod snippets_js;
+pub mod detect;
+pub mod version;
+pub mod locale;
+pub mod provider;
+pub mod element;
+pub mod vant;
+pub mod naive_ui;
+pub mod uni_app;
+pub mod custom;
+pub mod scan;
 
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
+use crate::completion_index::CompletionIndex;
+use component_doc::{ComponentDoc, EventParam};
+use locale::{Locale, Text};
+use provider::{FrameworkProvider, TagTable};
 
 /// Attribute info for a component attribute
 #[derive(Clone, Debug)]
 pub struct AttrInfo {
     pub attr_type: String, // "attribute" or "method"
-    pub description: String,
+    pub description: Text,
     pub values: Vec<String>,
+    /// Callback parameters of a `method` attribute (event payload)
+    pub params: Vec<EventParam>,
+    /// Library version that introduced the attribute
+    pub since: Option<String>,
+    /// Library version that deprecated the attribute
+    pub deprecated: Option<String>,
 }
 
-/// Cached framework data -- all 7 HashMaps in one struct
+impl AttrInfo {
+    pub fn since(mut self, version: &str) -> Self {
+        self.since = Some(version.to_string());
+        self
+    }
+
+    pub fn deprecated(mut self, version: &str) -> Self {
+        self.deprecated = Some(version.to_string());
+        self
+    }
+}
+
+/// Tag or script snippet with version markers
+#[derive(Clone, Debug)]
+pub struct TagInfo {
+    /// Snippet indented with tabs, see `render`
+    pub snippet: String,
+    /// Library version that introduced the tag
+    pub since: Option<String>,
+    /// Library version that deprecated the tag
+    pub deprecated: Option<String>,
+}
+
+impl TagInfo {
+    /// The snippet with its tabs replaced by the editor indentation
+    pub fn render(&self, tab_size: &str) -> String {
+        render_snippet(&self.snippet, tab_size)
+    }
+
+    pub fn since(mut self, version: &str) -> Self {
+        self.since = Some(version.to_string());
+        self
+    }
+
+    pub fn deprecated(mut self, version: &str) -> Self {
+        self.deprecated = Some(version.to_string());
+        self
+    }
+}
+
+impl From<String> for TagInfo {
+    fn from(snippet: String) -> Self {
+        TagInfo { snippet, since: None, deprecated: None }
+    }
+}
+
+impl From<&str> for TagInfo {
+    fn from(snippet: &str) -> Self {
+        TagInfo::from(snippet.to_string())
+    }
+}
+
+/// Replace the tabs of a snippet template with the editor indentation
+pub fn render_snippet(snippet: &str, tab_size: &str) -> String {
+    snippet.replace('\t', tab_size)
+}
+
+/// Framework data of a set of frameworks. Lookups are served from the provider tables (compiled
+/// into the binary for the built-in libraries) and filtered by the installed library version on
+/// the way out: entries newer than that version are left out, and `deprecated` is only kept on
+/// entries that are deprecated in it. Snippets keep their tabs and are rendered per request.
 pub struct FrameworkCache {
     pub key: String,
-    pub attributes: HashMap<String, HashMap<String, AttrInfo>>,
-    pub tags: HashMap<String, String>,
-    pub js_tags: HashMap<String, String>,
-    pub documents: HashMap<String, String>,
-    pub global_attributes: HashMap<String, AttrInfo>,
-    pub snippets_html: HashMap<String, String>,
-    pub snippets_js: HashMap<String, String>,
+    /// Language of the snippet text and rendered documents
+    pub locale: Locale,
+    /// Providers in framework order. Of several entries with the same name the last provider's
+    /// wins; the attributes of a tag are merged.
+    layers: Vec<Layer>,
+    completions: OnceCell<CompletionIndex>,
+}
+
+/// One framework of the cache: its provider and the installed version its data is filtered by
+struct Layer {
+    provider: Arc<dyn FrameworkProvider>,
+    installed: Option<version::Version>,
+}
+
+impl Layer {
+    /// The tag as of the installed version, its snippet placeholders translated with the
+    /// provider's own table. Tables are not merged: a phrase may translate differently per library.
+    fn tag(&self, info: TagInfo, locale: Locale) -> Option<TagInfo> {
+        let mut info = filter_tag(info, self.installed)?;
+        let translations = self.provider.snippet_translations();
+        if locale == Locale::En && !translations.is_empty() {
+            info.snippet = locale::translate_snippet(&info.snippet, translations);
+        }
+        Some(info)
+    }
+
+    /// All entries of a tag table of the provider, as of the installed version
+    fn tags<'a>(&'a self, table: TagTable<'a>, locale: Locale) -> impl Iterator<Item = (String, TagInfo)> + 'a {
+        table.iter().filter_map(move |(name, info)| Some((name.to_string(), self.tag(info, locale)?)))
+    }
+
+    /// The component doc as of the installed version, None when the component is newer.
+    /// Components without a tag snippet are always available.
+    fn component_doc(&self, tag: &str, mut doc: ComponentDoc) -> Option<ComponentDoc> {
+        let since = self.provider.tags().get(tag).and_then(|info| info.since);
+        if !version::is_available(since.as_deref(), self.installed) {
+            return None;
+        }
+        if doc.framework.is_empty() {
+            doc.framework = self.provider.group().to_string();
+        }
+        filter_doc(&mut doc, self.installed);
+        Some(doc)
+    }
+}
+
+impl FrameworkCache {
+    /// Completion items indexed by prefix, built on first use
+    pub fn completions(&self) -> &CompletionIndex {
+        self.completions.get_or_init(|| CompletionIndex::build(self))
+    }
+
+    /// Snippet of one tag
+    pub fn tag(&self, name: &str) -> Option<TagInfo> {
+        self.layers.iter().rev().find_map(|layer| layer.tag(layer.provider.tags().get(name)?, self.locale))
+    }
+
+    /// Snippets of all tags. Built once for the completion index.
+    pub fn tags(&self) -> HashMap<String, TagInfo> {
+        self.layers.iter().flat_map(|layer| layer.tags(layer.provider.tags(), self.locale)).collect()
+    }
+
+    /// Snippets of all script completions. Built once for the completion index, which requests
+    /// read them from.
+    pub fn js_tags(&self) -> HashMap<String, TagInfo> {
+        self.layers.iter().flat_map(|layer| layer.tags(layer.provider.js_tags(), self.locale)).collect()
+    }
+
+    /// Structured doc of one tag
+    pub fn component_doc(&self, tag: &str) -> Option<ComponentDoc> {
+        self.layers.iter().rev().find_map(|layer| layer.component_doc(tag, layer.provider.component_docs().get(tag)?))
+    }
+
+    /// Attributes and events of one tag, None for tags without a component doc.
+    /// Served from the completion index, see `all_attributes`.
+    pub fn attributes(&self, tag: &str) -> Option<&HashMap<String, AttrInfo>> {
+        self.completions().tag_attributes(tag)
+    }
+
+    /// One attribute or event of a tag
+    pub fn attribute(&self, tag: &str, name: &str) -> Option<&AttrInfo> {
+        self.completions().attribute(tag, name)
+    }
+
+    /// Attributes and events of all tags, converted from every component doc. Built once for the
+    /// completion index; requests use `attributes` and `attribute`.
+    pub fn all_attributes(&self) -> HashMap<String, HashMap<String, AttrInfo>> {
+        let mut result: HashMap<String, HashMap<String, AttrInfo>> = HashMap::new();
+        for layer in &self.layers {
+            for (tag, doc) in layer.provider.component_docs().iter() {
+                if let Some(doc) = layer.component_doc(tag, doc) {
+                    result.entry(tag.to_string()).or_default().extend(doc.attributes());
+                }
+            }
+        }
+        result
+    }
+
+    /// One attribute valid on any tag
+    pub fn global_attribute(&self, name: &str) -> Option<&AttrInfo> {
+        self.completions().global_attribute(name)
+    }
+
+    /// All attributes valid on any tag. Built once for the completion index; requests use
+    /// `global_attribute`.
+    pub fn global_attributes(&self) -> HashMap<String, AttrInfo> {
+        let mut result = HashMap::new();
+        for layer in &self.layers {
+            for (name, info) in layer.provider.global_attributes().iter() {
+                if let Some(info) = filter_attribute(info, layer.installed) {
+                    result.insert(name.to_string(), info);
+                }
+            }
+        }
+        result
+    }
+
+    /// The markdown document of one tag: the hand-written document, otherwise the rendered component doc
+    pub fn document(&self, tag: &str) -> Option<String> {
+        self.layers
+            .iter()
+            .rev()
+            .find_map(|layer| layer.provider.documents()?.get(tag).cloned())
+            .or_else(|| self.component_doc(tag).map(|doc| doc.to_markdown(tag, self.locale)))
+    }
 }
 
 static CACHE: Lazy<Mutex<Option<Arc<FrameworkCache>>>> = Lazy::new(|| Mutex::new(None));
 
-/// Build cache key from frameworks + tab_size
-fn make_cache_key(frameworks: &[String], tab_size: &str) -> String {
+/// Build cache key from frameworks + locale.
+/// Frameworks may carry the installed version (`element-ui@2.13.2`), which becomes part of the key.
+fn make_cache_key(frameworks: &[String], locale: Locale) -> String {
     let mut sorted = frameworks.to_vec();
     sorted.sort();
-    format!("{}|{}", sorted.join(","), tab_size)
+    format!("{}|{}", sorted.join(","), locale.as_str())
 }
 
 /// Get or build the cached framework data. Returns Arc for zero-copy sharing.
-pub fn get_cached_data(frameworks: &[String], tab_size: &str) -> Arc<FrameworkCache> {
-    let key = make_cache_key(frameworks, tab_size);
+pub fn get_cached_data(frameworks: &[String], locale: Locale) -> Arc<FrameworkCache> {
+    let key = make_cache_key(frameworks, locale);
     let mut guard = CACHE.lock().unwrap();
     if let Some(ref cached) = *guard {
         if cached.key == key {
@@ -50,118 +246,111 @@ pub fn get_cached_data(frameworks: &[String], tab_size: &str) -> Arc<FrameworkCa
     }
 
     // Cache miss -- rebuild all data
-    let cache = Arc::new(FrameworkCache {
-        key: key.clone(),
-        attributes: build_attributes(frameworks, tab_size),
-        tags: build_tags(frameworks, tab_size),
-        js_tags: build_js_tags(frameworks, tab_size),
-        documents: build_documents(frameworks, tab_size),
-        global_attributes: build_global_attributes(frameworks, tab_size),
-        snippets_html: snippets_html::get_snippets(tab_size),
-        snippets_js: snippets_js::get_snippets(tab_size),
-    });
+    let cache = Arc::new(build_cache(frameworks, locale));
     *guard = Some(Arc::clone(&cache));
     cache
 }
 
+/// Build the framework data for the given frameworks without touching the shared cache.
+/// Nothing is copied out of the provider tables.
+pub fn build_cache(frameworks: &[String], locale: Locale) -> FrameworkCache {
+    build_cache_with(frameworks, locale, &[])
+}
+
+/// `build_cache` with providers owned by the caller, found before the built-in ones.
+/// Workspace sessions keep the components of their project here, so that roots do not see
+/// each other's components.
+pub fn build_cache_with(frameworks: &[String], locale: Locale, providers: &[Arc<dyn FrameworkProvider>]) -> FrameworkCache {
+    FrameworkCache {
+        key: make_cache_key(frameworks, locale),
+        locale,
+        layers: resolve_providers(frameworks, providers),
+        completions: OnceCell::new(),
+    }
+}
+
 /// Invalidate the framework cache (call on workspace switch)
 pub fn invalidate_cache() {
     let mut guard = CACHE.lock().unwrap();
     *guard = None;
 }
 
-// ---- Internal builders (used only on cache miss) ----
+// ---- Internal helpers ----
 
-fn build_attributes(frameworks: &[String], _tab_size: &str) -> HashMap<String, HashMap<String, AttrInfo>> {
-    let mut result = HashMap::new();
-    for framework in frameworks {
-        if framework == "element-ui" || framework == "element-plus" {
-            let attrs = attribute::get_element_ui_attributes();
-            for (tag, tag_attrs) in attrs {
-                result.entry(tag).or_insert_with(HashMap::new).extend(tag_attrs);
-            }
-        }
-    }
-    result
+/// Resolve framework specs to providers with their installed version, `local` providers first
+fn resolve_providers(frameworks: &[String], local: &[Arc<dyn FrameworkProvider>]) -> Vec<Layer> {
+    frameworks
+        .iter()
+        .filter_map(|spec| {
+            let (framework, installed) = version::split_spec(spec);
+            local
+                .iter()
+                .find(|p| p.name() == framework)
+                .cloned()
+                .or_else(|| provider::find_provider(framework))
+                .map(|provider| Layer { provider, installed })
+        })
+        .collect()
 }
 
-fn build_tags(frameworks: &[String], tab_size: &str) -> HashMap<String, String> {
-    let mut result = HashMap::new();
-    for framework in frameworks {
-        if framework == "element-ui" || framework == "element-plus" {
-            result.extend(tag::get_element_ui_tags(tab_size));
-        }
+/// None for a tag newer than the installed version; the deprecation marker is cleared when not yet in effect
+fn filter_tag(mut info: TagInfo, installed: Option<version::Version>) -> Option<TagInfo> {
+    if !version::is_available(info.since.as_deref(), installed) {
+        return None;
+    }
+    if !version::is_deprecated(info.deprecated.as_deref(), installed) {
+        info.deprecated = None;
     }
-    result
+    Some(info)
 }
 
-fn build_js_tags(frameworks: &[String], tab_size: &str) -> HashMap<String, String> {
-    let mut result = HashMap::new();
-    for framework in frameworks {
-        if framework == "element-ui" || framework == "element-plus" {
-            result.extend(js_tag::get_element_ui_js_tags(tab_size));
-        }
+/// None for an attribute newer than the installed version; the deprecation marker is cleared when not yet in effect
+fn filter_attribute(mut info: AttrInfo, installed: Option<version::Version>) -> Option<AttrInfo> {
+    if !version::is_available(info.since.as_deref(), installed) {
+        return None;
+    }
+    if !version::is_deprecated(info.deprecated.as_deref(), installed) {
+        info.deprecated = None;
     }
-    result
+    Some(info)
 }
 
-fn build_documents(frameworks: &[String], _tab_size: &str) -> HashMap<String, String> {
-    let mut result = HashMap::new();
-    for framework in frameworks {
-        if framework == "element-ui" || framework == "element-plus" {
-            result.extend(document::get_element_ui_documents());
+/// Apply the same version filtering as `filter_attribute` to a component doc's props and events
+fn filter_doc(doc: &mut ComponentDoc, installed: Option<version::Version>) {
+    doc.props.retain(|prop| version::is_available(prop.since.as_deref(), installed));
+    for prop in doc.props.iter_mut() {
+        if !version::is_deprecated(prop.deprecated.as_deref(), installed) {
+            prop.deprecated = None;
         }
     }
-    result
-}
-
-fn build_global_attributes(frameworks: &[String], _tab_size: &str) -> HashMap<String, AttrInfo> {
-    let mut result = HashMap::new();
-    for framework in frameworks {
-        if framework == "element-ui" || framework == "element-plus" {
-            result.extend(global_attribute::get_element_ui_global_attributes());
+    doc.events.retain(|event| version::is_available(event.since.as_deref(), installed));
+    for event in doc.events.iter_mut() {
+        if !version::is_deprecated(event.deprecated.as_deref(), installed) {
+            event.deprecated = None;
         }
     }
-    result
-}
-
-// ---- Public API (kept for backward compatibility, now delegates to cache) ----
-
-/// Get merged attributes for given frameworks
-pub fn get_attributes(frameworks: &[String], tab_size: &str) -> HashMap<String, HashMap<String, AttrInfo>> {
-    get_cached_data(frameworks, tab_size).attributes.clone()
-}
-
-/// Get merged tags for given frameworks
-pub fn get_tags(frameworks: &[String], tab_size: &str) -> HashMap<String, String> {
-    get_cached_data(frameworks, tab_size).tags.clone()
 }
 
-/// Get merged JS tags for given frameworks
-pub fn get_js_tags(frameworks: &[String], tab_size: &str) -> HashMap<String, String> {
-    get_cached_data(frameworks, tab_size).js_tags.clone()
-}
+// ---- Public API (lookups through the shared cache) ----
 
-/// Get merged documents for given frameworks
-pub fn get_documents(frameworks: &[String], tab_size: &str) -> HashMap<String, String> {
-    get_cached_data(frameworks, tab_size).documents.clone()
+/// Get the markdown document of one tag
+pub fn get_document(frameworks: &[String], locale: Locale, tag: &str) -> Option<String> {
+    get_cached_data(frameworks, locale).document(tag)
 }
 
-/// Get merged global attributes for given frameworks
-pub fn get_global_attributes(frameworks: &[String], tab_size: &str) -> HashMap<String, AttrInfo> {
-    get_cached_data(frameworks, tab_size).global_attributes.clone()
+/// Get the structured doc of one tag
+pub fn get_component_doc(frameworks: &[String], locale: Locale, tag: &str) -> Option<ComponentDoc> {
+    get_cached_data(frameworks, locale).component_doc(tag)
 }
 
-/// Get vue HTML snippets
-pub fn get_vue_snippets_html(tab_size: &str) -> HashMap<String, String> {
-    // Snippets don't depend on frameworks, but we still cache them via the framework cache
-    // For standalone calls, just build directly
-    snippets_html::get_snippets(tab_size)
+/// Get vue HTML snippets in declaration order, indented with `tab_size`
+pub fn get_vue_snippets_html(tab_size: &str) -> Vec<(&'static str, String)> {
+    snippets_html::SNIPPETS.iter().map(|(key, snippet)| (*key, render_snippet(snippet, tab_size))).collect()
 }
 
-/// Get vue JS snippets
-pub fn get_vue_snippets_js(tab_size: &str) -> HashMap<String, String> {
-    snippets_js::get_snippets(tab_size)
+/// Get vue JS snippets in declaration order, indented with `tab_size`
+pub fn get_vue_snippets_js(tab_size: &str) -> Vec<(&'static str, String)> {
+    snippets_js::SNIPPETS.iter().map(|(key, snippet)| (*key, render_snippet(snippet, tab_size))).collect()
 }
 
 #[cfg(test)]
@@ -175,24 +364,93 @@ mod tests {
         // --- Cache hit ---
         invalidate_cache(); // start clean
         let frameworks = vec!["element-ui".to_string()];
-        let data1 = get_cached_data(&frameworks, "  ");
-        let data2 = get_cached_data(&frameworks, "  ");
+        let data1 = get_cached_data(&frameworks, Locale::ZhCn);
+        let data2 = get_cached_data(&frameworks, Locale::ZhCn);
         assert!(Arc::ptr_eq(&data1, &data2), "second call should return same Arc (cache hit)");
 
         // --- Cache invalidation ---
         invalidate_cache();
-        let data3 = get_cached_data(&frameworks, "  ");
+        let data3 = get_cached_data(&frameworks, Locale::ZhCn);
         assert!(!Arc::ptr_eq(&data1, &data3), "after invalidation, should be a new Arc");
         assert_eq!(data1.key, data3.key, "keys should still match");
 
-        // --- Cache key change (tab_size) ---
-        let data4 = get_cached_data(&frameworks, "    ");
-        assert!(!Arc::ptr_eq(&data3, &data4), "different tab_size => cache miss");
-        assert_ne!(data3.key, data4.key);
+        // --- Single lookups agree with the merged views ---
+        let tags = data3.tags();
+        assert_eq!(tags["el-button"].snippet, data3.tag("el-button").unwrap().snippet);
+        assert_eq!(data3.attributes("el-button").unwrap().len(), data3.all_attributes()["el-button"].len());
 
         // --- Cache key change (frameworks) ---
         let frameworks2 = vec!["element-plus".to_string()];
-        let data5 = get_cached_data(&frameworks2, "  ");
-        assert_ne!(data4.key, data5.key, "different frameworks => different key");
+        let data5 = get_cached_data(&frameworks2, Locale::ZhCn);
+        assert_ne!(data3.key, data5.key, "different frameworks => different key");
+
+        // --- Cache key change (installed version) ---
+        let frameworks3 = vec!["element-plus@2.4.0".to_string()];
+        let data6 = get_cached_data(&frameworks3, Locale::ZhCn);
+        assert_ne!(data5.key, data6.key, "different version => different key");
+
+        // --- Cache key change (locale) ---
+        let data7 = get_cached_data(&frameworks3, Locale::En);
+        assert_ne!(data6.key, data7.key, "different locale => different key");
+        assert_eq!(data7.locale, Locale::En);
+    }
+
+    #[test]
+    fn test_version_filtering() {
+        let old = build_cache(&["element-ui@2.6.0".to_string()], Locale::ZhCn);
+        let new = build_cache(&["element-ui@2.15.14".to_string()], Locale::ZhCn);
+        assert!(old.tag("el-image").is_none());
+        assert!(!old.tags().contains_key("el-image"));
+        assert!(new.tag("el-image").is_some());
+
+        let older = build_cache(&["element-ui@2.10.0".to_string()], Locale::ZhCn);
+        assert!(older.attribute("el-image", "preview-src-list").is_none());
+        assert!(older.attribute("el-image", "src").is_some());
+        assert!(old.attributes("el-image").is_none());
+        assert!(!old.all_attributes().contains_key("el-image"));
+        assert!(old.component_doc("el-image").is_none());
+        assert!(new.attribute("el-image", "preview-src-list").is_some());
+    }
+
+    #[test]
+    fn test_deprecated_markers() {
+        let plus = build_cache(&["element-plus@2.4.0".to_string()], Locale::ZhCn);
+        assert!(plus.tag("el-submenu").unwrap().deprecated.is_some());
+        assert!(plus.tag("el-sub-menu").unwrap().deprecated.is_none());
+
+        let ui = build_cache(&["element-ui@2.15.14".to_string()], Locale::ZhCn);
+        assert!(ui.tag("el-submenu").unwrap().deprecated.is_none());
+        assert!(ui.tag("el-sub-menu").is_none());
+
+        assert_eq!(plus.attribute("el-button", "size").unwrap().values, vec!["large", "default", "small"]);
+        assert!(plus.attribute("el-button", "link").is_some());
+        let old_plus = build_cache(&["element-plus@2.0.0".to_string()], Locale::ZhCn);
+        assert!(old_plus.attribute("el-button", "link").is_none());
+    }
+
+    #[test]
+    fn test_snippet_translation() {
+        let frameworks = vec!["element-ui".to_string()];
+        let js_tags = build_cache(&frameworks, Locale::ZhCn).js_tags();
+        assert!(js_tags["el-conform"].snippet.contains("'此操作将永久删除该文件, 是否继续?', '提示'"));
+        let js_tags = build_cache(&frameworks, Locale::En).js_tags();
+        let snippet = &js_tags["el-conform"].snippet;
+        assert!(snippet.contains("'This will permanently delete the file. Continue?', 'Warning'"));
+        assert!(snippet.contains("confirmButtonText: 'OK'"));
+
+        // Each library translates its own snippets: 提示 is Warning in Element and Tip in uni-app,
+        // 确定 is OK in Element and Confirm in Naive UI
+        let mixed = vec!["element-ui".to_string(), "uni-app".to_string(), "naive-ui".to_string()];
+        let js_tags = build_cache(&mixed, Locale::En).js_tags();
+        assert!(js_tags["el-conform"].snippet.contains("'Warning'"));
+        assert!(js_tags["uni.showModal"].snippet.contains("${1:Tip}"));
+        assert!(js_tags["n-dialog:error"].snippet.contains("${4:Confirm}"));
+
+        let data = build_cache(&frameworks, Locale::ZhCn);
+        let button_type = data.attribute("el-button", "type").unwrap();
+        assert_eq!(button_type.description.get(Locale::ZhCn), "类型");
+        assert_eq!(button_type.description.get(Locale::En), "button type");
+        // No English text yet: falls back to Chinese
+        assert_eq!(data.attribute("el-button", "size").unwrap().description.get(Locale::En), "尺寸");
     }
 }
@@ -0,0 +1,66 @@
+use super::compiled::{NAIVE_UI_DOCS, NAIVE_UI_JS_TAGS, NAIVE_UI_TAGS};
+use super::provider::{DataTable, DocTable, FrameworkProvider, TagTable};
+
+/// English placeholder text for the Naive UI snippets
+const SNIPPET_TRANSLATIONS: &[(&str, &str)] = &[
+    ("警告", "Warning"),
+    ("成功", "Success"),
+    ("错误", "Error"),
+    ("确定", "Confirm"),
+    ("取消", "Cancel"),
+];
+
+/// Naive UI (Vue 3). Feedback APIs are composables, so the script snippets are Composition API style.
+pub struct NaiveUiProvider;
+
+impl FrameworkProvider for NaiveUiProvider {
+    fn name(&self) -> &str {
+        "naive-ui"
+    }
+
+    fn packages(&self) -> &[&str] {
+        &["naive-ui"]
+    }
+
+    fn tags(&self) -> TagTable<'_> {
+        DataTable::Compiled(&NAIVE_UI_TAGS)
+    }
+
+    fn js_tags(&self) -> TagTable<'_> {
+        DataTable::Compiled(&NAIVE_UI_JS_TAGS)
+    }
+
+    fn component_docs(&self) -> DocTable<'_> {
+        DataTable::Compiled(&NAIVE_UI_DOCS)
+    }
+
+    fn snippet_translations(&self) -> &[(&'static str, &'static str)] {
+        SNIPPET_TRANSLATIONS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::framework_data::locale::Locale;
+    use crate::framework_data::{build_cache, detect};
+
+    #[test]
+    fn test_naive_ui_data() {
+        let data = build_cache(&["naive-ui".to_string()], Locale::ZhCn);
+        assert!(data.tag("n-data-table").unwrap().snippet.starts_with("<n-data-table"));
+
+        // Feedback APIs are composables, not instance properties
+        let js = data.js_tags();
+        assert_eq!(js["n-use-message"].snippet, "const ${1:message} = useMessage()");
+        assert!(js.values().all(|info| !info.snippet.contains("this.$")));
+
+        assert_eq!(data.attribute("n-data-table", "update:page").unwrap().attr_type, "method");
+        assert!(data.attribute("n-button", "type").unwrap().values.contains(&"tertiary".to_string()));
+    }
+
+    #[test]
+    fn test_detect_naive_ui() {
+        let pkg = r#"{ "dependencies": { "naive-ui": "^2.38.1" } }"#;
+        assert_eq!(detect::detect_frameworks(pkg, None)[0].spec, "naive-ui@2.38.1");
+    }
+}
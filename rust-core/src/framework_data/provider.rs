@@ -0,0 +1,187 @@
+use super::compiled::{StaticAttr, StaticDoc, StaticTag, Table};
+use super::component_doc::ComponentDoc;
+use super::{AttrInfo, TagInfo};
+use super::element::{AntDesignVueProvider, ElementPlusProvider, ElementUiProvider};
+use super::naive_ui::NaiveUiProvider;
+use super::uni_app::UniAppProvider;
+use super::vant::VantProvider;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Entries of a provider by name: a table compiled into the binary, or a map built at runtime.
+/// Entries are converted when looked up, so a compiled table is never copied as a whole.
+pub enum DataTable<'a, S: 'static, V> {
+    Empty,
+    Compiled(&'static Table<S>),
+    Owned(&'a HashMap<String, V>),
+}
+
+pub type TagTable<'a> = DataTable<'a, StaticTag, TagInfo>;
+pub type DocTable<'a> = DataTable<'a, StaticDoc, ComponentDoc>;
+pub type AttrTable<'a> = DataTable<'a, StaticAttr, AttrInfo>;
+
+impl<'a, S: 'static, V> DataTable<'a, S, V>
+where
+    V: Clone + for<'s> From<&'s S>,
+{
+    pub fn get(&self, name: &str) -> Option<V> {
+        match self {
+            DataTable::Empty => None,
+            DataTable::Compiled(table) => table.get(name).map(V::from),
+            DataTable::Owned(map) => map.get(name).cloned(),
+        }
+    }
+
+    pub fn contains_key(&self, name: &str) -> bool {
+        match self {
+            DataTable::Empty => false,
+            DataTable::Compiled(table) => table.contains_key(name),
+            DataTable::Owned(map) => map.contains_key(name),
+        }
+    }
+
+    /// All entries, converted one by one
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (&'a str, V)> + 'a> {
+        match *self {
+            DataTable::Empty => Box::new(std::iter::empty()),
+            DataTable::Compiled(table) => Box::new(table.entries().map(|(name, entry)| (*name, V::from(entry)))),
+            DataTable::Owned(map) => Box::new(map.iter().map(|(name, entry)| (name.as_str(), entry.clone()))),
+        }
+    }
+}
+
+/// A component library that contributes framework data.
+/// Every data method defaults to empty, so a provider only implements what it ships.
+/// The attributes of a tag are generated from its component doc.
+pub trait FrameworkProvider: Send + Sync {
+    /// Framework name used in framework specs (`element-ui`, `element-ui@2.13.2`)
+    fn name(&self) -> &str;
+
+    /// npm packages whose presence in package.json indicates this framework
+    fn packages(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Detection group. Only the highest-priority provider of a group is detected.
+    fn group(&self) -> &str {
+        self.name()
+    }
+
+    /// Tag snippets for template completion, indented with tabs
+    fn tags(&self) -> TagTable<'_> {
+        DataTable::Empty
+    }
+
+    /// Snippets for script completion, indented with tabs
+    fn js_tags(&self) -> TagTable<'_> {
+        DataTable::Empty
+    }
+
+    /// Structured documentation per tag
+    fn component_docs(&self) -> DocTable<'_> {
+        DataTable::Empty
+    }
+
+    /// Attributes valid on any tag (directives such as `v-loading`)
+    fn global_attributes(&self) -> AttrTable<'_> {
+        DataTable::Empty
+    }
+
+    /// Hand-written markdown hover documents per tag, shown instead of the rendered component doc.
+    /// Only runtime providers write them, so there is no compiled form.
+    fn documents(&self) -> Option<&HashMap<String, String>> {
+        None
+    }
+
+    /// `(中文, English)` pairs for the placeholder text in the tag and JS snippets,
+    /// applied when completing in English
+    fn snippet_translations(&self) -> &[(&'static str, &'static str)] {
+        &[]
+    }
+}
+
+/// Providers shipped with the extension, in detection priority order
+static BUILTIN: Lazy<Vec<Arc<dyn FrameworkProvider>>> = Lazy::new(|| {
+    vec![
+        Arc::new(ElementPlusProvider) as Arc<dyn FrameworkProvider>,
+        Arc::new(ElementUiProvider),
+        Arc::new(AntDesignVueProvider),
+        Arc::new(VantProvider),
+        Arc::new(NaiveUiProvider),
+        Arc::new(UniAppProvider),
+    ]
+});
+
+/// Whether `name` is the framework name of a provider shipped with the extension
+pub fn is_builtin(name: &str) -> bool {
+    BUILTIN.iter().any(|p| p.name() == name)
+}
+
+/// Check a framework name for a provider defined by the project. Names end up in cache keys
+/// (`a,b|tab`) and specs (`name@version`), and must not replace a built-in provider.
+pub fn check_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.contains(|c: char| c.is_whitespace() || c == ',' || c == '|' || c == '@') {
+        return Err(format!("`{}` is not a framework name: expected a non-empty name without spaces, `,`, `|` or `@`", name));
+    }
+    if is_builtin(name) {
+        return Err(format!("`{}` is the name of a built-in framework, choose another name", name));
+    }
+    Ok(())
+}
+
+/// Find a built-in provider by framework name
+pub fn find_provider(name: &str) -> Option<Arc<dyn FrameworkProvider>> {
+    BUILTIN.iter().find(|p| p.name() == name).cloned()
+}
+
+/// The built-in providers in detection priority order
+pub fn providers() -> &'static [Arc<dyn FrameworkProvider>] {
+    &BUILTIN
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework_data::locale::Locale;
+    use crate::framework_data::{build_cache_with, detect};
+
+    struct TestProvider(HashMap<String, TagInfo>);
+
+    impl FrameworkProvider for TestProvider {
+        fn name(&self) -> &str {
+            "test-provider-ui"
+        }
+
+        fn packages(&self) -> &[&str] {
+            &["test-provider-ui"]
+        }
+
+        fn tags(&self) -> TagTable<'_> {
+            DataTable::Owned(&self.0)
+        }
+    }
+
+    #[test]
+    fn test_local_provider() {
+        let frameworks = vec!["test-provider-ui".to_string()];
+        assert!(build_cache_with(&frameworks, Locale::ZhCn, &[]).tags().is_empty());
+
+        let tags = HashMap::from([("tp-button".to_string(), TagInfo::from("<tp-button>$1</tp-button>"))]);
+        let providers: Vec<Arc<dyn FrameworkProvider>> = vec![Arc::new(TestProvider(tags))];
+        assert!(build_cache_with(&frameworks, Locale::ZhCn, &providers).tag("tp-button").is_some());
+        assert!(find_provider("test-provider-ui").is_none());
+
+        let pkg = r#"{ "dependencies": { "test-provider-ui": "^1.0.0" } }"#;
+        let detected = detect::detect_frameworks_with(pkg, None, &providers);
+        assert_eq!(detected[0].spec, "test-provider-ui@1.0.0");
+    }
+
+    #[test]
+    fn test_builtin_names() {
+        assert!(check_name("x-ui").is_ok());
+        assert!(check_name("my lib").is_err());
+        assert!(check_name("vant").unwrap_err().contains("built-in"));
+        assert!(find_provider("element-ui").is_some());
+    }
+}
@@ -0,0 +1,192 @@
+use super::component_doc::{event, prop, slot, ComponentDoc};
+use super::provider::{self, DataTable, DocTable, FrameworkProvider, TagTable};
+use super::TagInfo;
+use crate::sfc::{self, SfcComponent};
+use crate::traverse;
+use crate::util::to_kebab_case;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Result of scanning a component library directory
+#[derive(Clone, Debug, Default)]
+pub struct ScanResult {
+    /// Framework name of the library
+    pub framework: String,
+    /// Tag names of the components found
+    pub tags: Vec<String>,
+    /// Why the library was not loaded
+    pub error: Option<String>,
+}
+
+/// A component found in the library
+#[derive(Clone, Debug)]
+pub struct ScannedComponent {
+    pub tag: String,
+    /// Absolute path of the `.vue` file
+    pub path: String,
+    pub sfc: SfcComponent,
+}
+
+/// Framework data generated from the single-file components of a library directory
+pub struct LibraryProvider {
+    name: String,
+    tags: HashMap<String, TagInfo>,
+    docs: HashMap<String, ComponentDoc>,
+}
+
+impl LibraryProvider {
+    pub fn new(name: &str, components: &[ScannedComponent]) -> Self {
+        LibraryProvider {
+            name: name.to_string(),
+            tags: components.iter().map(|c| (c.tag.clone(), tag_snippet(c).into())).collect(),
+            docs: components.iter().map(|c| (c.tag.clone(), component_doc(c))).collect(),
+        }
+    }
+}
+
+impl FrameworkProvider for LibraryProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tags(&self) -> TagTable<'_> {
+        DataTable::Owned(&self.tags)
+    }
+
+    fn component_docs(&self) -> DocTable<'_> {
+        DataTable::Owned(&self.docs)
+    }
+}
+
+/// `<x-search-form :model="$1">$0</x-search-form>`, with required props pre-filled
+fn tag_snippet(component: &ScannedComponent) -> String {
+    let mut attrs = String::new();
+    let mut index = 1;
+    for prop in component.sfc.props.iter().filter(|p| p.required) {
+        attrs.push_str(&format!(" :{}=\"${}\"", to_kebab_case(&prop.name), index));
+        index += 1;
+    }
+    format!("<{tag}{attrs}>${index}</{tag}>", tag = component.tag)
+}
+
+/// Props, emits and slots of the component, linking to its source file
+fn component_doc(component: &ScannedComponent) -> ComponentDoc {
+    let path = component.path.replace('\\', "/");
+    let mut doc = ComponentDoc::new(format!("file:///{}", path.trim_start_matches('/')));
+    for sfc_prop in &component.sfc.props {
+        let mut prop = prop(&to_kebab_case(&sfc_prop.name), "", &sfc_prop.prop_type);
+        prop.values = sfc_prop.values.clone();
+        prop.default = sfc_prop.default.clone();
+        prop.required = sfc_prop.required;
+        doc = doc.prop(prop);
+    }
+    for name in &component.sfc.emits {
+        doc = doc.event(event(name, ""));
+    }
+    for name in &component.sfc.slots {
+        doc = doc.slot(slot(name, ""));
+    }
+    doc
+}
+
+/// Find every `.vue` file under `library_dir` and extract its public API. The directory was chosen
+/// explicitly, so its `dist` or `build` directories are searched too, only the dependencies of the
+/// library are skipped.
+/// The tag comes from the component `name` option, else the file name (`index.vue` uses its directory),
+/// converted to kebab-case and prefixed with `tag_prefix` when it does not already start with it.
+pub fn scan_components(library_dir: &str, tag_prefix: Option<&str>) -> Vec<ScannedComponent> {
+    let mut components: Vec<ScannedComponent> = Vec::new();
+    for file in traverse::search_in(library_dir, &["node_modules"], ".vue", "", false, "", "") {
+        // index.vue is reported twice: as `index` and under its directory name
+        if file.name == "index" {
+            continue;
+        }
+        let path = Path::new(library_dir).join(&file.path);
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let sfc = sfc::parse_sfc(&content);
+        let mut tag = to_kebab_case(sfc.name.as_deref().unwrap_or(&file.name));
+        if let Some(prefix) = tag_prefix.filter(|p| !p.is_empty()) {
+            if !tag.starts_with(prefix) {
+                tag = format!("{}{}", prefix, tag);
+            }
+        }
+        if components.iter().any(|c| c.tag == tag) {
+            continue;
+        }
+        components.push(ScannedComponent { tag, path: path.to_string_lossy().to_string(), sfc });
+    }
+    components
+}
+
+/// Scan a component library directory into a provider for framework `framework`. A directory
+/// without components is an error, it is usually a wrong path.
+pub fn scan_library(framework: &str, library_dir: &str, tag_prefix: Option<&str>) -> Result<(LibraryProvider, ScanResult), String> {
+    provider::check_name(framework)?;
+    let components = scan_components(library_dir, tag_prefix);
+    if components.is_empty() {
+        return Err(format!("no `.vue` components found in {}", library_dir));
+    }
+    let tags = components.iter().map(|c| c.tag.clone()).collect();
+    let result = ScanResult { framework: framework.to_string(), tags, error: None };
+    Ok((LibraryProvider::new(framework, &components), result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework_data::locale::Locale;
+    use crate::framework_data::build_cache_with;
+    use crate::test_util::TempDir;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_scan_component_library() {
+        let root = TempDir::new("scan");
+        let form_dir = root.join("search-form");
+        fs::create_dir_all(&form_dir).unwrap();
+        fs::write(form_dir.join("index.vue"), r#"<template><form><slot /></form></template>
+<script>
+export default {
+  name: 'SearchForm',
+  props: {
+    model: { type: Object, required: true },
+    size: { type: String, default: 'medium', validator: v => ['small', 'medium'].includes(v) }
+  },
+  emits: ['search']
+}
+</script>"#).unwrap();
+        // Build output of the library itself is scanned, its dependencies are not
+        fs::create_dir_all(root.join("dist")).unwrap();
+        fs::write(root.join("dist/CrudTable.vue"), r#"<script setup>
+defineProps({ data: Array })
+</script>"#).unwrap();
+        fs::create_dir_all(root.join("node_modules/dep")).unwrap();
+        fs::write(root.join("node_modules/dep/Dep.vue"), "<template><div /></template>").unwrap();
+
+        let (library, result) = scan_library("scan-test-ui", &root.root(), Some("x-")).unwrap();
+        let mut tags = result.tags.clone();
+        tags.sort();
+        assert_eq!(tags, vec!["x-crud-table", "x-search-form"]);
+        let empty = scan_library("scan-test-ui", &root.join("node_modules/none").to_string_lossy(), None);
+        assert!(empty.err().unwrap().starts_with("no `.vue` components found"));
+
+        let providers: Vec<Arc<dyn FrameworkProvider>> = vec![Arc::new(library)];
+        let data = build_cache_with(&["scan-test-ui".to_string()], Locale::ZhCn, &providers);
+        assert_eq!(data.tag("x-search-form").unwrap().snippet, "<x-search-form :model=\"$1\">$2</x-search-form>");
+        let attrs = data.all_attributes();
+        assert_eq!(attrs["x-search-form"]["size"].values, vec!["small", "medium"]);
+        assert_eq!(attrs["x-search-form"]["search"].attr_type, "method");
+        assert!(attrs["x-crud-table"].contains_key("data"));
+        let doc = data.component_doc("x-search-form").unwrap().to_markdown("x-search-form", Locale::ZhCn);
+        assert!(doc.starts_with("[scan-test-ui：file:///"));
+        assert!(doc.contains("| model | （必填） | Object | — | — |"));
+        assert!(doc.contains("| size | — | String | small / medium | 'medium' |"));
+
+        // Built-in providers cannot be replaced
+        let builtin = scan_library("element-ui", &root.root(), None);
+        assert!(builtin.err().unwrap().contains("built-in"));
+    }
+}
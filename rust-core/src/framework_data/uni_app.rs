@@ -0,0 +1,76 @@
+use super::compiled::{UNI_APP_DOCS, UNI_APP_GLOBAL_ATTRIBUTES, UNI_APP_JS_TAGS, UNI_APP_TAGS};
+use super::provider::{AttrTable, DataTable, DocTable, FrameworkProvider, TagTable};
+
+/// English placeholder text for the uni-app snippets
+const SNIPPET_TRANSLATIONS: &[(&str, &str)] = &[
+    ("提交", "Submit"),
+    ("加载中", "Loading"),
+    ("提示", "Tip"),
+];
+
+/// uni-app built-in components and `uni.*` APIs.
+/// Events use the Vue `@tap` form; the mini program `bindtap` equivalent is noted in descriptions.
+pub struct UniAppProvider;
+
+impl FrameworkProvider for UniAppProvider {
+    fn name(&self) -> &str {
+        "uni-app"
+    }
+
+    fn packages(&self) -> &[&str] {
+        &["@dcloudio/uni-app"]
+    }
+
+    fn tags(&self) -> TagTable<'_> {
+        DataTable::Compiled(&UNI_APP_TAGS)
+    }
+
+    fn js_tags(&self) -> TagTable<'_> {
+        DataTable::Compiled(&UNI_APP_JS_TAGS)
+    }
+
+    fn global_attributes(&self) -> AttrTable<'_> {
+        DataTable::Compiled(&UNI_APP_GLOBAL_ATTRIBUTES)
+    }
+
+    fn component_docs(&self) -> DocTable<'_> {
+        DataTable::Compiled(&UNI_APP_DOCS)
+    }
+
+    fn snippet_translations(&self) -> &[(&'static str, &'static str)] {
+        SNIPPET_TRANSLATIONS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::framework_data::locale::Locale;
+    use crate::framework_data::{build_cache, detect};
+
+    #[test]
+    fn test_uni_app_data() {
+        let data = build_cache(&["uni-app".to_string()], Locale::ZhCn);
+        let tags = data.tags();
+        for tag in ["view", "text", "scroll-view", "swiper", "picker", "navigator"] {
+            assert!(tags.contains_key(tag), "missing {}", tag);
+        }
+        assert!(data.js_tags()["uni.navigateTo"].snippet.starts_with("uni.navigateTo("));
+
+        assert!(data.attribute("navigator", "open-type").unwrap().values.contains(&"switchTab".to_string()));
+        assert!(data.attribute("swiper", "change").unwrap().description.zh.contains("bindchange"));
+
+        let events = data.global_attributes();
+        assert!(data.global_attribute("tap").is_some());
+        assert_eq!(events["tap"].attr_type, "method");
+        assert!(events["tap"].description.zh.contains("bindtap"));
+    }
+
+    #[test]
+    fn test_detect_uni_app() {
+        let pkg = r#"{ "dependencies": { "@dcloudio/uni-app": "3.0.0-4020920240930001", "vue": "^3.4.21" } }"#;
+        let detected = detect::detect_frameworks(pkg, None);
+        assert_eq!(detected[0].name, "uni-app");
+        assert_eq!(detected[0].package, "@dcloudio/uni-app");
+        assert_eq!(detected[0].spec, "uni-app@3.0.0");
+    }
+}
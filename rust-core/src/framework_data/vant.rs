@@ -0,0 +1,87 @@
+use super::compiled::{VANT_DOCS, VANT_GLOBAL_ATTRIBUTES, VANT_JS_TAGS, VANT_TAGS};
+use super::provider::{AttrTable, DataTable, DocTable, FrameworkProvider, TagTable};
+
+/// English placeholder text for the Vant snippets
+const SNIPPET_TRANSLATIONS: &[(&str, &str)] = &[
+    ("请输入搜索关键词", "Search keywords"),
+    ("描述文字", "Description"),
+    ("提示内容", "Message"),
+    ("加载中...", "Loading..."),
+    ("成功文案", "Success"),
+    ("失败文案", "Failed"),
+    ("弹窗内容", "Content"),
+    ("通知内容", "Notification"),
+    ("没有更多了", "No more"),
+    ("标题", "Title"),
+    ("返回", "Back"),
+];
+
+/// Vant mobile components. Vant 2 (Vue 2) and Vant 3/4 (Vue 3) share one data set;
+/// the API differences are expressed with `since`/`deprecated` markers.
+pub struct VantProvider;
+
+impl FrameworkProvider for VantProvider {
+    fn name(&self) -> &str {
+        "vant"
+    }
+
+    fn packages(&self) -> &[&str] {
+        &["vant"]
+    }
+
+    fn tags(&self) -> TagTable<'_> {
+        DataTable::Compiled(&VANT_TAGS)
+    }
+
+    fn js_tags(&self) -> TagTable<'_> {
+        DataTable::Compiled(&VANT_JS_TAGS)
+    }
+
+    fn global_attributes(&self) -> AttrTable<'_> {
+        DataTable::Compiled(&VANT_GLOBAL_ATTRIBUTES)
+    }
+
+    fn component_docs(&self) -> DocTable<'_> {
+        DataTable::Compiled(&VANT_DOCS)
+    }
+
+    fn snippet_translations(&self) -> &[(&'static str, &'static str)] {
+        SNIPPET_TRANSLATIONS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::framework_data::locale::Locale;
+    use crate::framework_data::{build_cache, detect};
+
+    #[test]
+    fn test_vant_versions() {
+        let vant2 = build_cache(&["vant@2.12.54".to_string()], Locale::ZhCn);
+        let js = vant2.js_tags();
+        assert!(js.contains_key("van-toast"));
+        assert!(js["van-toast"].deprecated.is_none());
+        assert!(!js.contains_key("van-show-toast"));
+        let popup = vant2.attributes("van-popup").unwrap();
+        assert!(popup.contains_key("get-container"));
+        assert!(!popup.contains_key("teleport"));
+        assert!(vant2.tag("van-popup:show").is_none());
+
+        let vant4 = build_cache(&["vant@4.8.0".to_string()], Locale::ZhCn);
+        let js = vant4.js_tags();
+        assert!(js["van-show-toast"].snippet.starts_with("showToast("));
+        assert!(js["van-toast"].deprecated.is_some());
+        assert!(vant4.attribute("van-popup", "v-model").unwrap().deprecated.is_some());
+        let button = vant4.attributes("van-button").unwrap();
+        assert_eq!(button["type"].values, vec!["default", "primary", "success", "warning", "danger"]);
+        assert_eq!(button["click"].attr_type, "method");
+    }
+
+    #[test]
+    fn test_detect_vant() {
+        let pkg = r#"{ "dependencies": { "vue": "^3.3.0", "vant": "^4.8.0" } }"#;
+        let detected = detect::detect_frameworks(pkg, None);
+        assert_eq!(detected[0].name, "vant");
+        assert_eq!(detected[0].spec, "vant@4.8.0");
+    }
+}
@@ -0,0 +1,148 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A `major.minor.patch` library version. Pre-release and build metadata are ignored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Version { major, minor, patch }
+    }
+
+    /// Parse a concrete version such as `2.15.14`, `v2.4` or `4.0.0-rc.1`.
+    /// Missing or wildcard (`x`, `*`) components are read as 0.
+    pub fn parse(text: &str) -> Option<Version> {
+        let text = text.trim().trim_start_matches(['v', '=']);
+        let core = text.split(['-', '+']).next()?;
+        let mut parts = core.split('.');
+        let major = parse_component(parts.next()?)?;
+        let minor = parts.next().map(parse_component).unwrap_or(Some(0))?;
+        let patch = parts.next().map(parse_component).unwrap_or(Some(0))?;
+        Some(Version { major, minor, patch })
+    }
+
+    /// Lowest version allowed by an npm semver range (`^2.13.2`, `~2.4.0`, `>=2.0.0 <3`, `2.x`).
+    /// Tags (`latest`), URLs and `workspace:`/`npm:` protocols that carry no version yield `None`.
+    pub fn from_range(range: &str) -> Option<Version> {
+        let range = range.trim();
+        let range = match range.strip_prefix("npm:") {
+            // `npm:element-plus@^2.4.0` aliases
+            Some(alias) => alias.rsplit_once('@').map(|(_, r)| r)?,
+            None => range.strip_prefix("workspace:").unwrap_or(range),
+        };
+        // Take the lowest alternative of `a || b`
+        range
+            .split("||")
+            .filter_map(|alt| {
+                // First comparator of the set is the lower bound (`>=2.0.0 <3`, `2.0.0 - 2.4.0`)
+                let first = alt.split_whitespace().next()?;
+                let first = first.trim_start_matches(['^', '~', '>', '=']);
+                if first.starts_with('<') {
+                    return Some(Version::new(0, 0, 0));
+                }
+                Version::parse(first)
+            })
+            .min()
+    }
+}
+
+/// Split a framework spec such as `element-ui@2.13.2` into name and version.
+/// A bare name (`element-ui`) or an unparsable version yields `None` for the version.
+pub fn split_spec(spec: &str) -> (&str, Option<Version>) {
+    // Skip index 0 so scoped packages (`@dcloudio/uni-app`) keep their leading `@`
+    match spec.get(1..).and_then(|rest| rest.rfind('@')) {
+        Some(idx) => (&spec[..idx + 1], Version::parse(&spec[idx + 2..])),
+        None => (spec, None),
+    }
+}
+
+/// Whether an item introduced in `since` exists in `installed`. Unknown versions are treated as latest.
+pub fn is_available(since: Option<&str>, installed: Option<Version>) -> bool {
+    match (since.and_then(Version::parse), installed) {
+        (Some(since), Some(installed)) => installed >= since,
+        _ => true,
+    }
+}
+
+/// Whether an item deprecated in `deprecated` is deprecated in `installed`. Unknown versions are treated as latest.
+pub fn is_deprecated(deprecated: Option<&str>, installed: Option<Version>) -> bool {
+    match (deprecated.and_then(Version::parse), installed) {
+        (Some(deprecated), Some(installed)) => installed >= deprecated,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+fn parse_component(part: &str) -> Option<u64> {
+    match part {
+        "x" | "X" | "*" | "" => Some(0),
+        _ => part.parse().ok(),
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Version::parse("2.15.14"), Some(Version::new(2, 15, 14)));
+        assert_eq!(Version::parse("v2.4"), Some(Version::new(2, 4, 0)));
+        assert_eq!(Version::parse("4.0.0-rc.1"), Some(Version::new(4, 0, 0)));
+        assert_eq!(Version::parse("latest"), None);
+    }
+
+    #[test]
+    fn test_from_range() {
+        assert_eq!(Version::from_range("^2.13.2"), Some(Version::new(2, 13, 2)));
+        assert_eq!(Version::from_range("~2.4.0"), Some(Version::new(2, 4, 0)));
+        assert_eq!(Version::from_range(">=2.0.0 <3"), Some(Version::new(2, 0, 0)));
+        assert_eq!(Version::from_range("2.x"), Some(Version::new(2, 0, 0)));
+        assert_eq!(Version::from_range("^1.0.0 || ^2.1.0"), Some(Version::new(1, 0, 0)));
+        assert_eq!(Version::from_range("npm:element-plus@^2.4.0"), Some(Version::new(2, 4, 0)));
+        assert_eq!(Version::from_range("latest"), None);
+        assert!(Version::new(2, 10, 0) > Version::new(2, 9, 9));
+    }
+
+    #[test]
+    fn test_split_spec() {
+        assert_eq!(split_spec("element-ui@2.13.2"), ("element-ui", Some(Version::new(2, 13, 2))));
+        assert_eq!(split_spec("element-plus"), ("element-plus", None));
+        assert_eq!(split_spec("@dcloudio/uni-app"), ("@dcloudio/uni-app", None));
+        assert_eq!(split_spec("@dcloudio/uni-app@3.0.0"), ("@dcloudio/uni-app", Some(Version::new(3, 0, 0))));
+    }
+
+    #[test]
+    fn test_markers() {
+        let v213 = Version::parse("2.13.0");
+        assert!(!is_available(Some("2.15.0"), v213));
+        assert!(is_available(Some("2.8.0"), v213));
+        assert!(is_available(Some("2.15.0"), None));
+        assert!(is_deprecated(Some("2.13.0"), v213));
+        assert!(!is_deprecated(Some("2.15.0"), v213));
+        assert!(!is_deprecated(None, v213));
+    }
+}
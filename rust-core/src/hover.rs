@@ -1,27 +1,168 @@
-use crate::framework_data;
+use crate::completion;
+use crate::framework_data::{locale::Locale, AttrInfo, FrameworkCache};
+
+/// Characters that end a hover word on the TypeScript side
+const WORD_DELIMITERS: &[char] = &[' ', '<', '>', '"', '\'', '.', '\\', '=', ':', '\n', '\r', '\t'];
 
 /// Provide hover documentation for a word
-pub fn provide_hover_info(word: &str, frameworks: &[String], tab_size: &str) -> Option<String> {
-    let documents = framework_data::get_documents(frameworks, tab_size);
-    documents.get(word).cloned()
+pub fn provide_hover_info(word: &str, data: &FrameworkCache) -> Option<String> {
+    data.document(word)
+}
+
+/// Provide hover documentation for a word in its document context.
+/// `text_before` is the document text before the cursor. Inside an open tag the word is looked up as an
+/// attribute or event of that tag, then as a global attribute; otherwise as a tag.
+pub fn provide_hover_at(text_before: &str, word: &str, data: &FrameworkCache) -> Option<String> {
+    // The part of the word left of the cursor is already in `text_before`
+    let before_word = text_before.trim_end_matches(|c: char| !WORD_DELIMITERS.contains(&c));
+    if let Some(tag) = completion::match_pre_tag_from_text(before_word) {
+        let prefix_start = before_word
+            .rfind(|c: char| c.is_whitespace() || matches!(c, '<' | '"' | '\'' | '='))
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let raw = format!("{}{}", &before_word[prefix_start..], word);
+        if let Some(hover) = provide_attr_hover(&tag.text, &raw, data) {
+            return Some(hover);
+        }
+    }
+    provide_hover_info(word, data)
+}
+
+/// Hover for an attribute as written in the template: `type`, `:visible`, `v-bind:data`,
+/// `@click`, `v-on:change`, `@update:value`, `v-model:show`
+pub fn provide_attr_hover(tag: &str, attr: &str, data: &FrameworkCache) -> Option<String> {
+    let locale = data.locale;
+    let (name, attr_type) = if let Some(event) = attr.strip_prefix('@').or_else(|| attr.strip_prefix("v-on:")) {
+        (event, "method")
+    } else {
+        (attr.strip_prefix(':').or_else(|| attr.strip_prefix("v-bind:")).unwrap_or(attr), "attribute")
+    };
+    if name.is_empty() {
+        return None;
+    }
+
+    if let Some(info) = data.attribute(tag, name).filter(|info| info.attr_type == attr_type) {
+        return Some(if attr_type == "method" {
+            event_markdown(Some(tag), name, info, locale)
+        } else {
+            let doc = data.component_doc(tag);
+            let prop = doc.as_ref().and_then(|doc| doc.find_prop(name));
+            let prop_type = prop.map(|p| p.prop_type.as_str()).unwrap_or("");
+            let default = prop.and_then(|p| p.default.as_deref());
+            attribute_markdown(Some(tag), name, info, prop_type, default, locale)
+        });
+    }
+
+    let info = data.global_attribute(name).filter(|info| info.attr_type == attr_type)?;
+    Some(if attr_type == "method" {
+        event_markdown(None, name, info, locale)
+    } else {
+        attribute_markdown(None, name, info, "", None, locale)
+    })
+}
+
+fn heading(tag: Option<&str>, name: &str, info: &AttrInfo, locale: Locale) -> String {
+    let mut text = match tag {
+        Some(tag) => format!("**{}** `{}`", tag, name),
+        None => format!("`{}`", name),
+    };
+    if let Some(version) = &info.deprecated {
+        text.push_str(&match locale {
+            Locale::ZhCn => format!(" — 已废弃（{}）", version),
+            Locale::En => format!(" — deprecated ({})", version),
+        });
+    }
+    text.push_str("\n\n");
+    if !info.description.is_empty() {
+        text.push_str(info.description.get(locale));
+        text.push_str("\n\n");
+    }
+    text
+}
+
+fn attribute_markdown(
+    tag: Option<&str>,
+    name: &str,
+    info: &AttrInfo,
+    prop_type: &str,
+    default: Option<&str>,
+    locale: Locale,
+) -> String {
+    let mut text = heading(tag, name, info, locale);
+    if !prop_type.is_empty() {
+        text.push_str(&format!("{}`{}`\n\n", locale.pick("类型：", "Type: "), prop_type));
+    }
+    if !info.values.is_empty() {
+        let values: Vec<String> = info.values.iter().map(|v| format!("`{}`", v)).collect();
+        text.push_str(&format!("{}{}\n\n", locale.pick("可选值：", "Accepted values: "), values.join(" / ")));
+    }
+    if let Some(default) = default {
+        text.push_str(&format!("{}`{}`\n\n", locale.pick("默认值：", "Default: "), default));
+    }
+    text.trim_end().to_string()
+}
+
+fn event_markdown(tag: Option<&str>, name: &str, info: &AttrInfo, locale: Locale) -> String {
+    let mut text = heading(tag, &format!("@{}", name), info, locale);
+    if !info.params.is_empty() {
+        let params: Vec<String> = info
+            .params
+            .iter()
+            .map(|p| if p.param_type.is_empty() { p.name.clone() } else { format!("{}: {}", p.name, p.param_type) })
+            .collect();
+        text.push_str(&format!("{}`({})`\n\n", locale.pick("回调参数：", "Parameters: "), params.join(", ")));
+    }
+    text.trim_end().to_string()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::framework_data;
 
     #[test]
     fn test_hover_existing_tag() {
-        let frameworks = vec!["element-ui".to_string()];
-        let result = provide_hover_info("el-button", &frameworks, "  ");
+        let data = framework_data::build_cache(&["element-ui".to_string()], Locale::ZhCn);
+        let result = provide_hover_info("el-button", &data);
         assert!(result.is_some());
-        assert!(result.unwrap().contains("element"));
+        let doc = result.unwrap();
+        assert!(doc.contains("element"));
+        // Rendered from the structured component doc
+        assert!(doc.contains("| plain | 朴素按钮 | boolean | — | false |"));
     }
 
     #[test]
     fn test_hover_unknown_tag() {
-        let frameworks = vec!["element-ui".to_string()];
-        let result = provide_hover_info("unknown-tag", &frameworks, "  ");
+        let data = framework_data::build_cache(&["element-ui".to_string()], Locale::ZhCn);
+        let result = provide_hover_info("unknown-tag", &data);
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_hover_attribute() {
+        let data = framework_data::build_cache(&["element-ui".to_string()], Locale::ZhCn);
+        let hover = provide_hover_at("<template>\n  <el-button ty", "type", &data).unwrap();
+        assert!(hover.starts_with("**el-button** `type`"));
+        assert!(hover.contains("类型：`string`"));
+        assert!(hover.contains("`primary`"));
+
+        let hover = provide_hover_at("<el-dialog :visible.sync=\"show\" :", "title", &data).unwrap();
+        assert!(hover.starts_with("**el-dialog** `title`"));
+
+        let hover = provide_hover_at("<el-input @", "@clear", &data).unwrap();
+        assert!(hover.starts_with("**el-input** `@clear`"));
+
+        let hover = provide_hover_at("<el-table @", "@selection-change", &data).unwrap();
+        assert!(hover.contains("回调参数：`(selection: array)`"));
+        // `clear` is an event, not a prop
+        assert!(provide_attr_hover("el-input", "clear", &data).is_none());
+
+        // Global attribute
+        let hover = provide_hover_at("<el-table v-", "v-loading", &data).unwrap();
+        assert!(hover.starts_with("`v-loading`"));
+
+        // Outside a tag the word is a tag name
+        let hover = provide_hover_at("<div></div>\n<", "el-button", &data).unwrap();
+        assert!(hover.contains("| plain |"));
+    }
 }
@@ -5,27 +5,43 @@ pub mod framework_data;
 pub mod traverse;
 pub mod util;
 pub mod completion;
+pub mod completion_index;
+pub mod ranking;
 pub mod hover;
 pub mod definition;
 pub mod assist;
+pub mod auto_import;
+pub mod path_completion;
+pub mod sfc;
+pub mod workspace;
+#[cfg(test)]
+mod test_util;
 
-use napi::bindgen_prelude::*;
-use std::collections::HashMap;
+use framework_data::locale::Locale;
+use framework_data::FrameworkCache;
+use std::sync::Arc;
+
+/// Framework data from the shared cache, for the functions that take the frameworks on every call
+fn shared_data(frameworks: &[String], locale: Option<String>) -> Arc<FrameworkCache> {
+    framework_data::get_cached_data(frameworks, Locale::parse(locale.as_deref()))
+}
 
 /// Initialize the framework provider with detected frameworks
 #[napi]
 pub fn init_frameworks(package_json_content: String) -> Vec<String> {
-    let mut frameworks = Vec::new();
-    if package_json_content.contains("element-plus") {
-        frameworks.push("element-plus".to_string());
-    }
-    if package_json_content.contains("element-ui") {
-        frameworks.push("element-ui".to_string());
-    }
-    if package_json_content.contains("ant-design-vue") {
-        frameworks.push("ant-design-vue".to_string());
-    }
-    frameworks
+    framework_data::detect::detect_frameworks(&package_json_content, None)
+        .into_iter()
+        .map(|f| f.name)
+        .collect()
+}
+
+/// Detect frameworks from package.json dependencies, with declared range and installed version
+#[napi]
+pub fn detect_frameworks(
+    package_json_content: String,
+    project_root: Option<String>,
+) -> Vec<framework_data::detect::DetectedFramework> {
+    framework_data::detect::detect_frameworks(&package_json_content, project_root.as_deref())
 }
 
 /// Search for vue files in the project
@@ -48,14 +64,34 @@ pub fn search_files(
     )
 }
 
-/// Get completion suggestions for tags
+/// Get completion suggestions for tags starting with `prefix`, at most `max_count` of them.
+/// Children of the tag enclosing `text_before` rank first.
 #[napi]
 pub fn get_tag_completions(
     frameworks: Vec<String>,
     tab_size: String,
     use_vue_snippets: bool,
+    locale: Option<String>,
+    text_before: Option<String>,
+    prefix: Option<String>,
+    max_count: Option<u32>,
 ) -> Vec<completion::CompletionSuggestion> {
-    completion::get_tag_suggestions(&frameworks, &tab_size, use_vue_snippets)
+    let parent = text_before.as_deref().and_then(completion::find_parent_tag);
+    completion::get_tag_suggestions(
+        &shared_data(&frameworks, locale),
+        &tab_size,
+        use_vue_snippets,
+        parent.as_deref(),
+        &ranking::Usage::new(),
+        prefix.as_deref().unwrap_or(""),
+        completion::max_count(max_count),
+    )
+}
+
+/// Range a tag or attribute completion replaces at `character` of line `line`
+#[napi]
+pub fn get_completion_range(line_text: String, line: u32, character: u32) -> completion::CompletionRange {
+    completion::word_range(line, &line_text, character)
 }
 
 /// Get completion suggestions for JS tags
@@ -64,19 +100,28 @@ pub fn get_js_tag_completions(
     frameworks: Vec<String>,
     tab_size: String,
     use_vue_snippets: bool,
+    locale: Option<String>,
 ) -> Vec<completion::CompletionSuggestion> {
-    completion::get_js_tag_suggestions(&frameworks, &tab_size, use_vue_snippets)
+    completion::get_js_tag_suggestions(&shared_data(&frameworks, locale), &tab_size, use_vue_snippets)
 }
 
-/// Get attribute completions for a given tag
+/// Get attribute completions for a given tag starting with `prefix`, at most `max_count` of them
 #[napi]
 pub fn get_attr_completions(
     tag: String,
     frameworks: Vec<String>,
-    tab_size: String,
     attr_type: String,
+    locale: Option<String>,
+    prefix: Option<String>,
+    max_count: Option<u32>,
 ) -> Vec<completion::CompletionSuggestion> {
-    completion::get_attr_suggestions(&tag, &frameworks, &tab_size, &attr_type)
+    completion::get_attr_suggestions(
+        &tag,
+        &shared_data(&frameworks, locale),
+        &attr_type,
+        prefix.as_deref().unwrap_or(""),
+        completion::max_count(max_count),
+    )
 }
 
 /// Get attribute value completions
@@ -85,25 +130,82 @@ pub fn get_attr_value_completions(
     tag: String,
     attr: String,
     frameworks: Vec<String>,
-    tab_size: String,
+    locale: Option<String>,
 ) -> Vec<completion::CompletionSuggestion> {
-    completion::get_attr_value_suggestions(&tag, &attr, &frameworks, &tab_size)
+    completion::get_attr_value_suggestions(&tag, &attr, &shared_data(&frameworks, locale))
 }
 
-/// Get element tag label suggestions (tag names from attribute data)
+/// Get element tag label suggestions (tag names from attribute data) starting with `prefix`,
+/// at most `max_count` of them
 #[napi]
 pub fn get_element_tag_labels(
     frameworks: Vec<String>,
-    tab_size: String,
     extension_name: String,
+    locale: Option<String>,
+    text_before: Option<String>,
+    prefix: Option<String>,
+    max_count: Option<u32>,
+) -> Vec<completion::CompletionSuggestion> {
+    let parent = text_before.as_deref().and_then(completion::find_parent_tag);
+    completion::get_element_tag_label_suggestions(
+        &shared_data(&frameworks, locale),
+        &extension_name,
+        parent.as_deref(),
+        &ranking::Usage::new(),
+        prefix.as_deref().unwrap_or(""),
+        completion::max_count(max_count),
+    )
+}
+
+/// Get slot name and slot prop completions inside an open tag
+#[napi]
+pub fn get_slot_completions(
+    text_before: String,
+    frameworks: Vec<String>,
+    locale: Option<String>,
+) -> Vec<completion::CompletionSuggestion> {
+    completion::get_slot_suggestions(&text_before, &shared_data(&frameworks, locale))
+}
+
+/// Get the edit that adds an empty handler for an event to the document script
+#[napi]
+pub fn get_event_handler_edit(
+    content: String,
+    tag: String,
+    event: String,
+    frameworks: Vec<String>,
+    tab_size: String,
+    locale: Option<String>,
+) -> Option<assist::EditOperation> {
+    completion::get_event_handler_edit(&content, &tag, &event, &shared_data(&frameworks, locale), &tab_size)
+}
+
+/// Get method completions for `this.$refs.name.` from the tag with `ref="name"` in the document
+#[napi]
+pub fn get_ref_method_completions(
+    content: String,
+    text_before: String,
+    frameworks: Vec<String>,
+    locale: Option<String>,
 ) -> Vec<completion::CompletionSuggestion> {
-    completion::get_element_tag_label_suggestions(&frameworks, &tab_size, &extension_name)
+    completion::get_ref_method_suggestions(&content, &text_before, &shared_data(&frameworks, locale))
 }
 
 /// Provide hover information for a word
 #[napi]
-pub fn provide_hover(word: String, frameworks: Vec<String>, tab_size: String) -> Option<String> {
-    hover::provide_hover_info(&word, &frameworks, &tab_size)
+pub fn provide_hover(word: String, frameworks: Vec<String>, locale: Option<String>) -> Option<String> {
+    hover::provide_hover_info(&word, &shared_data(&frameworks, locale))
+}
+
+/// Provide hover information for a word, resolving attributes and events against the enclosing tag
+#[napi]
+pub fn provide_hover_at(
+    text_before: String,
+    word: String,
+    frameworks: Vec<String>,
+    locale: Option<String>,
+) -> Option<String> {
+    hover::provide_hover_at(&text_before, &word, &shared_data(&frameworks, locale))
 }
 
 /// Check if a line is a close tag
@@ -202,15 +304,17 @@ pub fn extract_vue_props(file_content: String) -> Vec<completion::CompletionSugg
     completion::extract_props_from_vue(&file_content)
 }
 
-/// Get import suggestions from vue files
+/// Get import suggestions from vue files, with import paths written as `options` describe
 #[napi]
 pub fn get_import_suggestions(
     search_text: String,
     vue_files: Vec<traverse::VueFile>,
     document_path: String,
     project_root: String,
+    options: Option<completion::ImportPathOptions>,
 ) -> Vec<completion::CompletionSuggestion> {
-    completion::get_import_suggestion_items(&search_text, &vue_files, &document_path, &project_root)
+    let options = options.unwrap_or_default();
+    completion::get_import_suggestion_items(&search_text, &vue_files, &document_path, &project_root, &options)
 }
 
 /// Search for definition in file (Vue2 in-file jump)
@@ -0,0 +1,532 @@
+//! Completion of the paths written in module specifiers (`import x from './`, `import('@/`,
+//! `require('lodash/`) and in asset references (`<img src="@/assets/`, `url(./`). Each completion
+//! replaces the path segment at the cursor. Directories insert their name and a `/`, modules their
+//! name without the extensions `definition::resolve_file` finds again, and packages under
+//! `node_modules` offer their `exports` subpaths. Asset references pointing to no file are reported
+//! by `find_missing_assets`.
+
+use crate::completion::{CompletionKind, CompletionRange, CompletionSuggestion, ImportPathOptions};
+use crate::definition;
+use crate::ranking;
+use crate::traverse::{self, VueFile};
+use crate::util;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+
+static RE_MODULE_SPECIFIER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:\bfrom\s*|\bimport\s*\(?\s*|\brequire\s*\(\s*)['"]([^'"]*)$"#).unwrap()
+});
+
+/// A static `src` or a CSS `url()` ending at the cursor. `:src` is a binding, see `RE_MODULE_SPECIFIER`.
+static RE_ASSET_VALUE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:\ssrc\s*=\s*["']|\burl\(\s*["']?)([^"')]*)$"#).unwrap()
+});
+
+static RE_ASSET_REFERENCE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:\s(src)\s*=\s*["']|:src\s*=\s*["']\s*(require)\(\s*['"]|\b(url)\(\s*["']?)([^"'()\s]+)"#).unwrap()
+});
+
+/// Second alias of the alias directory, as Nuxt and Vue CLI projects write it
+const HOME_ALIAS: &str = "~";
+
+/// Directory served at `/`, for references such as `/favicon.ico`
+const PUBLIC_DIR: &str = "public";
+
+/// Extensions of the files `find_assets` indexes
+const ASSET_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "svg", "webp", "avif", "ico", "bmp",
+    "mp4", "webm", "mp3", "wav", "woff", "woff2", "ttf", "otf", "eot",
+];
+
+/// A reference to an asset file that does not exist, in UTF-16 columns
+#[napi(object)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingAsset {
+    pub line: u32,
+    pub start_char: u32,
+    pub end_char: u32,
+    pub path: String,
+}
+
+/// The path typed between its opening quote or parenthesis and the cursor
+struct TypedPath<'a> {
+    line_text: &'a str,
+    line: u32,
+    character: u32,
+    /// Byte offset of the path in `line_text`
+    start: usize,
+    typed: &'a str,
+}
+
+impl<'a> TypedPath<'a> {
+    fn at(re: &Regex, line_text: &'a str, line: u32, character: u32) -> Option<Self> {
+        let cursor = util::byte_offset(line_text, character);
+        let path = re.captures(&line_text[..cursor])?.get(1)?;
+        Some(TypedPath { line_text, line, character, start: path.start(), typed: path.as_str() })
+    }
+
+    /// The typed directories, up to and with the last `/`
+    fn directory(&self) -> &'a str {
+        &self.typed[..self.typed.rfind('/').map_or(0, |i| i + 1)]
+    }
+
+    /// Range from `offset` bytes into the path to the end of the segment at the cursor
+    fn range(&self, offset: usize) -> CompletionRange {
+        let cursor = self.start + self.typed.len();
+        let after = &self.line_text[cursor..];
+        let segment_end = cursor + after.find(['/', '\'', '"', ')']).unwrap_or(after.len());
+        CompletionRange {
+            line: self.line,
+            start_char: util::utf16_column(self.line_text, self.start + offset),
+            insert_end_char: self.character,
+            replace_end_char: util::utf16_column(self.line_text, segment_end),
+        }
+    }
+
+    fn segment_range(&self) -> CompletionRange {
+        self.range(self.directory().len())
+    }
+}
+
+/// Path completions for the module specifier at `character` of line `line`, `None` when the
+/// cursor is not inside one. Relative paths start from `document_path`, the aliases of `options`
+/// and `~` from the alias directory, anything else from `node_modules`.
+pub fn get_module_path_suggestions(
+    line_text: &str,
+    line: u32,
+    character: u32,
+    document_path: &str,
+    project_root: &str,
+    options: &ImportPathOptions,
+) -> Option<Vec<CompletionSuggestion>> {
+    let path = TypedPath::at(&RE_MODULE_SPECIFIER, line_text, line, character)?;
+    let directory = path.directory();
+    let segment_range = path.segment_range();
+
+    if path.typed.starts_with('.') {
+        let base = Path::new(document_path).parent().unwrap_or(Path::new("")).join(directory);
+        return Some(directory_suggestions(&base, document_path, Some(options), &segment_range));
+    }
+    if let Some(rest) = strip_alias(directory, options) {
+        let base = Path::new(project_root).join(alias_path(options)).join(rest);
+        return Some(directory_suggestions(&base, document_path, Some(options), &segment_range));
+    }
+
+    let node_modules = Path::new(project_root).join("node_modules");
+    let Some(package) = package_name(path.typed) else {
+        return Some(package_suggestions(&node_modules.join(directory), directory, &segment_range));
+    };
+    let package_dir = node_modules.join(package);
+    Some(export_suggestions(&package_dir, &path.range(package.len() + 1)).unwrap_or_else(|| {
+        let dir = package_dir.join(&directory[package.len() + 1..]);
+        directory_suggestions(&dir, document_path, Some(options), &segment_range)
+    }))
+}
+
+/// Asset files of the project: images, media and fonts outside `node_modules` and build output
+pub fn find_assets(project_root: &str, alias: &str, alias_path: &str) -> Vec<VueFile> {
+    let mut assets: Vec<VueFile> = traverse::search(project_root, "", "", true, alias, alias_path)
+        .into_iter()
+        .filter(|file| is_asset(&file.relative_path))
+        .collect();
+    // `index.png` is listed a second time under the name of its directory
+    assets.dedup_by(|a, b| a.relative_path == b.relative_path);
+    assets
+}
+
+fn is_asset(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ASSET_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+/// Path completions for the static `src` or CSS `url()` at `character` of line `line`, `None`
+/// when the cursor is not inside one. Until a `/` is typed the `assets` of the project matching
+/// the typed name are offered with their whole path, then the entries of the typed directory:
+/// relative to `document_path`, the alias directory, or `public` for paths starting with `/`.
+pub fn get_asset_path_suggestions(
+    line_text: &str,
+    line: u32,
+    character: u32,
+    document_path: &str,
+    project_root: &str,
+    assets: &[VueFile],
+    options: &ImportPathOptions,
+) -> Option<Vec<CompletionSuggestion>> {
+    let path = TypedPath::at(&RE_ASSET_VALUE, line_text, line, character)?;
+    let typed = path.typed;
+    if !typed.contains('/') {
+        let range = path.range(0);
+        let items = assets
+            .iter()
+            .filter_map(|asset| {
+                let name = asset.relative_path.rsplit('/').next().unwrap_or_default();
+                let score = if typed.is_empty() { 0 } else { ranking::fuzzy_score(name, typed)? };
+                let asset_path = asset_path(asset, document_path, project_root, options);
+                Some(CompletionSuggestion {
+                    label: name.to_string(),
+                    sort_text: ranking::score_key(score, &asset_path),
+                    insert_text: asset_path.clone(),
+                    kind: CompletionKind::File,
+                    detail: asset_path,
+                    range: Some(range.clone()),
+                    // Matched fuzzily here, the editor must not filter again
+                    filter_text: Some(typed.to_string()),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        return Some(items);
+    }
+
+    let directory = path.directory();
+    let root = Path::new(project_root);
+    let base = if let Some(rest) = directory.strip_prefix('/') {
+        root.join(PUBLIC_DIR).join(rest)
+    } else if let Some(rest) = strip_alias(directory, options) {
+        root.join(alias_path(options)).join(rest)
+    } else {
+        Path::new(document_path).parent().unwrap_or(Path::new("")).join(directory)
+    };
+    Some(directory_suggestions(&base, document_path, None, &path.segment_range()))
+}
+
+/// How an asset is written from `document_path`: files of `public` from the site root, the
+/// others relative or aliased like imports
+fn asset_path(asset: &VueFile, document_path: &str, project_root: &str, options: &ImportPathOptions) -> String {
+    match asset.relative_path.strip_prefix(PUBLIC_DIR).and_then(|rest| rest.strip_prefix('/')) {
+        Some(rest) => format!("/{}", rest),
+        None => options.path_to(asset, document_path, project_root),
+    }
+}
+
+/// Where an asset is referenced
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Reference {
+    /// A static `src`, which the bundler resolves only for `.`, `~`, `@` and `/` paths
+    Src,
+    /// `:src="require('...')"`
+    Require,
+    /// CSS `url()`, resolved relative to the document
+    Url,
+}
+
+/// The `src`, `:src="require()"` and `url()` references of a document that point to no file.
+/// Scripts are skipped, as are URLs, data URIs and interpolated paths.
+pub fn find_missing_assets(
+    content: &str,
+    document_path: &str,
+    project_root: &str,
+    options: &ImportPathOptions,
+) -> Vec<MissingAsset> {
+    let mut missing = Vec::new();
+    let mut in_script = false;
+    for (i, line) in content.lines().enumerate() {
+        in_script |= line.trim_start().starts_with("<script");
+        if in_script {
+            in_script = !line.contains("</script>");
+            continue;
+        }
+        for caps in RE_ASSET_REFERENCE.captures_iter(line) {
+            let reference = if caps.get(1).is_some() {
+                Reference::Src
+            } else if caps.get(2).is_some() {
+                Reference::Require
+            } else {
+                Reference::Url
+            };
+            let Some(path) = caps.get(4) else {
+                continue;
+            };
+            if asset_resolves(path.as_str(), reference, document_path, project_root, options) == Some(false) {
+                missing.push(MissingAsset {
+                    line: i as u32,
+                    start_char: util::utf16_column(line, path.start()),
+                    end_char: util::utf16_column(line, path.end()),
+                    path: path.as_str().to_string(),
+                });
+            }
+        }
+    }
+    missing
+}
+
+/// Whether the file `path` refers to exists, `None` for paths that are not project files
+fn asset_resolves(
+    path: &str,
+    reference: Reference,
+    document_path: &str,
+    project_root: &str,
+    options: &ImportPathOptions,
+) -> Option<bool> {
+    if path.contains("://") || path.starts_with("//") || path.starts_with("data:") || path.starts_with('#') || path.contains(['{', '$', '<']) {
+        return None;
+    }
+    // `logo.svg?inline`, `icons.svg#close`
+    let path = path.split(['?', '#']).next().unwrap_or(path);
+    let (file, is_absolute) = if let Some(rest) = path.strip_prefix('/') {
+        (format!("{}/{}", PUBLIC_DIR, rest), true)
+    } else if let Some(rest) = strip_alias(path, options) {
+        (format!("{}/{}", alias_path(options), rest), true)
+    } else if let Some(module) = path.strip_prefix('~') {
+        (format!("node_modules/{}", module), true)
+    } else if path.starts_with('.') || reference == Reference::Url {
+        (path.to_string(), false)
+    } else if reference == Reference::Require {
+        (format!("node_modules/{}", path), true)
+    } else {
+        return None;
+    };
+    Some(definition::resolve_file(document_path, &file, project_root, is_absolute).is_some())
+}
+
+/// The rest of `path` after the alias of `options` or `~`, and their `/`
+fn strip_alias<'a>(path: &'a str, options: &ImportPathOptions) -> Option<&'a str> {
+    let alias = options.alias.as_deref().unwrap_or("@");
+    [alias, HOME_ALIAS]
+        .into_iter()
+        .filter(|alias| !alias.is_empty())
+        .find_map(|alias| path.strip_prefix(alias)?.strip_prefix('/'))
+}
+
+fn alias_path(options: &ImportPathOptions) -> &str {
+    options.alias_path.as_deref().unwrap_or("src")
+}
+
+/// The package of a bare specifier once its name is complete: `lodash` of `lodash/f`,
+/// `@vue/shared` of `@vue/shared/`. `None` while the name is still being typed.
+fn package_name(specifier: &str) -> Option<&str> {
+    let segments = if specifier.starts_with('@') { 2 } else { 1 };
+    let end = specifier.match_indices('/').nth(segments - 1)?.0;
+    Some(&specifier[..end])
+}
+
+/// Packages under `node_modules`, or under its `@scope` directory `scope`. Scopes are offered as
+/// directories.
+fn package_suggestions(dir: &Path, scope: &str, range: &CompletionRange) -> Vec<CompletionSuggestion> {
+    visible_entries(dir)
+        .into_iter()
+        .filter(|(_, is_dir)| *is_dir)
+        .map(|(name, _)| {
+            if scope.is_empty() && name.starts_with('@') {
+                folder(name, range)
+            } else {
+                CompletionSuggestion {
+                    label: name.clone(),
+                    sort_text: format!("1{}", name),
+                    insert_text: name.clone(),
+                    kind: CompletionKind::Module,
+                    detail: format!("{}{}", scope, name),
+                    range: Some(range.clone()),
+                    ..Default::default()
+                }
+            }
+        })
+        .collect()
+}
+
+/// Subpaths listed by the `exports` of the package in `package_dir`, without patterns. `None` when
+/// the package does not list subpaths, so that its files can be imported directly.
+fn export_suggestions(package_dir: &Path, range: &CompletionRange) -> Option<Vec<CompletionSuggestion>> {
+    let content = fs::read_to_string(package_dir.join("package.json")).ok()?;
+    let manifest: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let exports = manifest.get("exports")?.as_object()?;
+    if !exports.keys().any(|key| key.starts_with("./")) {
+        return None;
+    }
+    Some(
+        exports
+            .keys()
+            .filter_map(|key| key.strip_prefix("./"))
+            .filter(|subpath| !subpath.is_empty() && !subpath.contains('*'))
+            .map(|subpath| CompletionSuggestion {
+                label: subpath.to_string(),
+                sort_text: format!("1{}", subpath),
+                insert_text: subpath.to_string(),
+                kind: CompletionKind::Module,
+                detail: "exports".to_string(),
+                range: Some(range.clone()),
+                ..Default::default()
+            })
+            .collect(),
+    )
+}
+
+/// Directories and files of `dir`, directories first. The edited file itself is left out. Files
+/// are written as imports when import `options` are given, with their full name otherwise.
+fn directory_suggestions(
+    dir: &Path,
+    document_path: &str,
+    options: Option<&ImportPathOptions>,
+    range: &CompletionRange,
+) -> Vec<CompletionSuggestion> {
+    visible_entries(dir)
+        .into_iter()
+        .filter(|(name, _)| dir.join(name) != Path::new(document_path))
+        .map(|(name, is_dir)| {
+            if is_dir {
+                return folder(name, range);
+            }
+            CompletionSuggestion {
+                label: name.clone(),
+                sort_text: format!("1{}", name),
+                insert_text: options.map_or_else(|| name.clone(), |options| import_name(dir, &name, options)),
+                kind: CompletionKind::File,
+                range: Some(range.clone()),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn folder(name: String, range: &CompletionRange) -> CompletionSuggestion {
+    CompletionSuggestion {
+        sort_text: format!("0{}", name),
+        insert_text: format!("{}/", name),
+        kind: CompletionKind::Folder,
+        range: Some(range.clone()),
+        label: name,
+        ..Default::default()
+    }
+}
+
+/// Names in `dir` that do not start with a `.`, sorted, with whether each is a directory
+fn visible_entries(dir: &Path) -> Vec<(String, bool)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut names: Vec<(String, bool)> = entries
+        .flatten()
+        .map(|entry| (entry.file_name().to_string_lossy().to_string(), entry.path().is_dir()))
+        .filter(|(name, _)| !name.starts_with('.'))
+        .collect();
+    names.sort();
+    names
+}
+
+/// The file name as imported: without its extension when `options` drops it and resolving the
+/// name without it finds this very file, not `Foo.vue` for `Foo.js`
+fn import_name(dir: &Path, name: &str, options: &ImportPathOptions) -> String {
+    let Some((stem, ext)) = name.rsplit_once('.') else {
+        return name.to_string();
+    };
+    if !options.drops_extension(ext) {
+        return name.to_string();
+    }
+    let resolved = definition::resolve_file("", stem, &dir.to_string_lossy(), true);
+    if resolved.is_some_and(|path| Path::new(&path) == dir.join(name)) {
+        stem.to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    fn write(root: &Path, file: &str, content: &str) {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_module_path_suggestions() {
+        let root = TempDir::new("module-paths");
+        write(&root, "src/views/Home.vue", "");
+        write(&root, "src/views/List.vue", "");
+        write(&root, "src/utils/format.js", "");
+        write(&root, "src/utils/date.ts", "");
+        write(&root, "src/utils/date.vue", "");
+        write(&root, "src/utils/http/index.js", "");
+        write(&root, "node_modules/lodash/debounce.js", "");
+        write(&root, "node_modules/@vue/shared/package.json", "{}");
+        write(&root, "node_modules/vant/package.json", r#"{"exports": {".": "./lib/index.js", "./es/*": "./es/*", "./lib/button": "./lib/button/index.js"}}"#);
+        let root_path = root.to_string_lossy().to_string();
+        let document = root.join("src/views/Home.vue").to_string_lossy().to_string();
+        let options = ImportPathOptions::default();
+        let complete = |line: &str| {
+            let character = line.find('|').unwrap();
+            let line = line.replace('|', "");
+            get_module_path_suggestions(&line, 0, character as u32, &document, &root_path, &options).map(|mut items| {
+                items.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+                items.into_iter().map(|s| s.insert_text).collect::<Vec<_>>()
+            })
+        };
+
+        // The edited file is left out
+        assert_eq!(complete("import List from './|'"), Some(vec!["List.vue".to_string()]));
+        assert_eq!(complete("import { format } from '../utils/f|'"), complete("const http = require('@/utils/|')"));
+        // `.ts` would resolve to `date.vue` without its extension
+        assert_eq!(complete("import('~/utils/|')").unwrap(), vec!["http/", "date.ts", "date.vue", "format"]);
+        assert_eq!(complete("import _ from '|'").unwrap(), vec!["@vue/", "lodash", "vant"]);
+        assert_eq!(complete("import { isArray } from '@vue/|'").unwrap(), vec!["shared"]);
+        assert_eq!(complete("import debounce from 'lodash/|'").unwrap(), vec!["debounce"]);
+        assert_eq!(complete("import Button from 'vant/|'").unwrap(), vec!["lib/button"]);
+        assert_eq!(complete("const a = '|'"), None);
+
+        // The path segment at the cursor is replaced, an exports subpath after the package name
+        let line = "import x from '../utils/fo/index'";
+        let items = get_module_path_suggestions(line, 2, 25, &document, &root_path, &options).unwrap();
+        assert_eq!(items[0].range, Some(CompletionRange { line: 2, start_char: 24, insert_end_char: 25, replace_end_char: 26 }));
+        let line = "import Button from 'vant/lib/b'";
+        let items = get_module_path_suggestions(line, 0, 30, &document, &root_path, &options).unwrap();
+        assert_eq!(items[0].range.as_ref().map(|r| r.start_char), Some(25));
+    }
+
+    #[test]
+    fn test_asset_paths() {
+        let root = TempDir::new("asset-paths");
+        write(&root, "src/views/Home.vue", "");
+        write(&root, "src/assets/logo.png", "");
+        write(&root, "src/assets/icons/close.svg", "");
+        write(&root, "public/favicon.ico", "");
+        write(&root, "src/main.js", "");
+        let root_path = root.to_string_lossy().to_string();
+        let document = root.join("src/views/Home.vue").to_string_lossy().to_string();
+        let options = ImportPathOptions::default();
+        let assets = find_assets(&root_path, "@", "src");
+        assert_eq!(assets.len(), 3);
+        let complete = |line: &str| {
+            let character = line.find('|').unwrap();
+            let line = line.replace('|', "");
+            get_asset_path_suggestions(&line, 0, character as u32, &document, &root_path, &assets, &options).map(|mut items| {
+                items.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+                items.into_iter().map(|s| s.insert_text).collect::<Vec<_>>()
+            })
+        };
+
+        assert_eq!(complete("<img src=\"|\">").unwrap(), vec!["/favicon.ico", "@/assets/icons/close.svg", "@/assets/logo.png"]);
+        assert_eq!(complete("<img class=\"logo\" src=\"lo|\">").unwrap(), vec!["@/assets/logo.png", "@/assets/icons/close.svg"]);
+        assert_eq!(complete("  background: url(@/assets/|);").unwrap(), vec!["icons/", "logo.png"]);
+        assert_eq!(complete("  background: url('../assets/icons/|');").unwrap(), vec!["close.svg"]);
+        assert_eq!(complete("<link href=\"/|\">"), None);
+        // Bindings are module specifiers
+        assert_eq!(complete("<el-image :src=\"require('@/assets/|')\" />"), None);
+
+        let content = [
+            "<template>",
+            "  <img src=\"@/assets/logo.png\"><img src=\"@/assets/missing.png\">",
+            "  <img src=\"https://example.com/a.png\"><img src=\"/favicon.ico\"><img src=\"images/runtime.png\">",
+            "  <el-image :src=\"require('../assets/gone.png')\" />",
+            "</template>",
+            "<script>",
+            "const icon = 'url(./nothing.png)'",
+            "</script>",
+            "<style>",
+            ".close { background: url(../assets/icons/close.svg?inline) }",
+            ".logo { background: url('./logo.png') }",
+            "</style>",
+        ]
+        .join("\n");
+        let missing = find_missing_assets(&content, &document, &root_path, &options);
+        let paths: Vec<(u32, &str)> = missing.iter().map(|m| (m.line, m.path.as_str())).collect();
+        assert_eq!(paths, vec![(1, "@/assets/missing.png"), (3, "../assets/gone.png"), (10, "./logo.png")]);
+        assert_eq!((missing[0].start_char, missing[0].end_char), (41, 61));
+    }
+}
@@ -0,0 +1,306 @@
+//! Relevance ranking of completion items. Items rank by how well their label matches the typed
+//! prefix, then by their context (children of the enclosing tag, attributes of the tag itself),
+//! then by how often the project already uses them. `Rank::sort_key` turns a rank into a fixed-width
+//! key with the label as tie-break, so the order never depends on hash map iteration.
+
+use crate::traverse;
+use crate::util::to_kebab_case;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// How often each tag is opened in the project's components, by kebab-case name
+pub type Usage = HashMap<String, u32>;
+
+static RE_OPEN_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"<([A-Za-z][\w-]*)").unwrap());
+
+/// Usage counts above this share the same rank
+const MAX_USAGE: u32 = 999_999;
+
+/// How well a label matches the typed prefix
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchQuality {
+    /// Nothing typed, or the label does not start with the prefix
+    #[default]
+    None,
+    Prefix,
+    /// The prefix ends at a `-` or `:` of the label, `el-table` for `el-table-column`
+    Segment,
+    Exact,
+}
+
+impl MatchQuality {
+    pub fn of(label: &str, prefix: &str) -> Self {
+        if prefix.is_empty() || !label.starts_with(prefix) {
+            MatchQuality::None
+        } else if label.len() == prefix.len() {
+            MatchQuality::Exact
+        } else if matches!(label.as_bytes()[prefix.len()], b'-' | b':') {
+            MatchQuality::Segment
+        } else {
+            MatchQuality::Prefix
+        }
+    }
+}
+
+/// Where an item comes from, relative to the cursor
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Context {
+    /// Vue snippets and global attributes
+    #[default]
+    Generic,
+    /// Framework tags and the attributes of the tag being edited
+    Specific,
+    /// Tags usually placed directly inside the enclosing tag
+    Child,
+}
+
+/// Fields in order of importance, so ranks compare field by field
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Rank {
+    pub quality: MatchQuality,
+    pub context: Context,
+    pub usage: u32,
+}
+
+impl Rank {
+    pub fn new(label: &str, prefix: &str, context: Context, usage: u32) -> Self {
+        Rank { quality: MatchQuality::of(label, prefix), context, usage: usage.min(MAX_USAGE) }
+    }
+
+    /// Quality, context and usage inverted and zero-padded, so that higher ranks sort first,
+    /// followed by the label
+    pub fn sort_key(&self, label: &str) -> String {
+        format!(
+            "{}{}{:06}{}",
+            MatchQuality::Exact as u8 - self.quality as u8,
+            Context::Child as u8 - self.context as u8,
+            MAX_USAGE - self.usage,
+            label
+        )
+    }
+}
+
+/// Sort key keeping items in the order they are listed
+pub fn position_key(position: usize) -> String {
+    format!("{:04}", position)
+}
+
+/// Fuzzy score of a name typed in full, ignoring case and separators: `user-list` for `UserList`
+pub const EXACT_NAME: u32 = 10_000;
+/// Fuzzy score of the initials of every word: `ul` for `UserList`
+pub const ACRONYM: u32 = 800;
+
+/// Lowercase words of a name, split at `-`, `_`, `.`, spaces and case changes.
+/// `UserList`, `user-list` and `user_list` all give `["user", "list"]`, `HTMLParser` gives `["html", "parser"]`.
+pub fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            words.extend((!word.is_empty()).then(|| std::mem::take(&mut word)));
+            continue;
+        }
+        let prev = if i > 0 { chars[i - 1] } else { ' ' };
+        let next = chars.get(i + 1).copied().unwrap_or(' ');
+        let boundary = c.is_uppercase()
+            && (prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next.is_lowercase()));
+        if boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        word.extend(c.to_lowercase());
+    }
+    words.extend((!word.is_empty()).then_some(word));
+    words
+}
+
+/// How well `query` matches `name`, `None` when its letters do not appear in order.
+/// A name typed in full scores `EXACT_NAME`, the initials of its words `ACRONYM`. Otherwise the
+/// letters are matched in order, preferring letters that start a word or follow the previous match,
+/// so `usrlst` and `userl` both find `UserList`. Case and separators are ignored.
+pub fn fuzzy_score(name: &str, query: &str) -> Option<u32> {
+    let query: Vec<char> = query.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return None;
+    }
+    let words = words(name);
+    let mut chars = Vec::new();
+    let mut word_start = Vec::new();
+    for word in &words {
+        for (i, c) in word.chars().enumerate() {
+            chars.push(c);
+            word_start.push(i == 0);
+        }
+    }
+    if chars == query {
+        return Some(EXACT_NAME);
+    }
+    if words.len() > 1 && words.iter().filter_map(|w| w.chars().next()).eq(query.iter().copied()) {
+        return Some(ACRONYM);
+    }
+
+    // best[i]: best score of the query so far with its last letter matched at chars[i]
+    let mut best: Vec<Option<u32>> = vec![None; chars.len()];
+    for (j, &q) in query.iter().enumerate() {
+        let mut next = vec![None; chars.len()];
+        // Best score ending at least two letters before i, so the match at i is not consecutive
+        let mut before_gap: Option<u32> = None;
+        for i in 0..chars.len() {
+            if i >= 2 {
+                before_gap = before_gap.max(best[i - 2]);
+            }
+            if chars[i] != q {
+                continue;
+            }
+            let letter = if word_start[i] { 6 } else { 1 };
+            next[i] = if j == 0 {
+                Some(letter)
+            } else {
+                let consecutive = if i >= 1 { best[i - 1].map(|score| score + letter + 3) } else { None };
+                consecutive.max(before_gap.map(|score| score + letter))
+            };
+        }
+        best = next;
+    }
+    best.into_iter().flatten().max().map(|score| score.min(ACRONYM - 1))
+}
+
+/// Sort key of a score, higher scores first, ties broken by label
+pub fn score_key(score: u32, label: &str) -> String {
+    format!("{:06}{}", MAX_USAGE - score.min(MAX_USAGE), label)
+}
+
+/// Usage count of a tag. Variants such as `el-button:primary` count as their tag.
+pub fn usage_of(usage: &Usage, tag: &str) -> u32 {
+    usage.get(tag.split(':').next().unwrap_or(tag)).copied().unwrap_or(0)
+}
+
+/// Count the tags opened in `content`, `<ElButton` counting as `el-button`
+fn count_tags(content: &str) -> Usage {
+    let mut usage = Usage::new();
+    for caps in RE_OPEN_TAG.captures_iter(content) {
+        *usage.entry(to_kebab_case(&caps[1])).or_insert(0) += 1;
+    }
+    usage
+}
+
+/// Tag usage of the project's `.vue` files, kept per file so that a saved file is counted again
+/// on its own
+#[derive(Default)]
+pub struct ProjectUsage {
+    /// Counts of each file, by path relative to the project root
+    files: HashMap<String, Usage>,
+    total: Usage,
+}
+
+impl ProjectUsage {
+    pub fn scan(root: &str) -> Self {
+        let mut usage = ProjectUsage::default();
+        for file in traverse::search(root, ".vue", "", false, "", "") {
+            if let Ok(content) = fs::read_to_string(Path::new(root).join(&file.relative_path)) {
+                usage.update(&file.relative_path, Some(&content));
+            }
+        }
+        usage
+    }
+
+    /// Usage counts of the whole project
+    pub fn total(&self) -> &Usage {
+        &self.total
+    }
+
+    pub fn contains(&self, relative_path: &str) -> bool {
+        self.files.contains_key(relative_path)
+    }
+
+    /// Count the tags of one file again, `None` when the file was deleted
+    pub fn update(&mut self, relative_path: &str, content: Option<&str>) {
+        for (tag, count) in self.files.remove(relative_path).unwrap_or_default() {
+            if let Some(total) = self.total.get_mut(&tag) {
+                *total -= count.min(*total);
+                if *total == 0 {
+                    self.total.remove(&tag);
+                }
+            }
+        }
+        if let Some(content) = content {
+            let usage = count_tags(content);
+            for (tag, count) in &usage {
+                *self.total.entry(tag.clone()).or_insert(0) += count;
+            }
+            self.files.insert(relative_path.to_string(), usage);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.files.clear();
+        self.total.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_rank_sort_key() {
+        assert_eq!(MatchQuality::of("el-table", "el-table"), MatchQuality::Exact);
+        assert_eq!(MatchQuality::of("el-table-column", "el-table"), MatchQuality::Segment);
+        assert_eq!(MatchQuality::of("el-tabs", "el-tab"), MatchQuality::Prefix);
+        assert_eq!(MatchQuality::of("el-tabs", ""), MatchQuality::None);
+
+        let key = |label, prefix, context, usage| Rank::new(label, prefix, context, usage).sort_key(label);
+        // Keys have a fixed width before the label, so 10 uses sort before 2 uses
+        assert!(key("el-input", "", Context::Specific, 10) < key("el-button", "", Context::Specific, 2));
+        assert!(key("el-table", "el-table", Context::Generic, 0) < key("el-table-column", "el-table", Context::Child, 50));
+        assert!(key("el-option", "", Context::Child, 0) < key("el-button", "", Context::Specific, 50));
+        assert!(key("el-button", "", Context::Specific, 0) < key("el-input", "", Context::Specific, 0));
+        assert!(position_key(2) < position_key(10));
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(words("UserList"), vec!["user", "list"]);
+        assert_eq!(words("user-list_item"), vec!["user", "list", "item"]);
+        assert_eq!(words("HTMLParser2"), vec!["html", "parser2"]);
+
+        assert_eq!(fuzzy_score("UserList", "userlist"), Some(EXACT_NAME));
+        assert_eq!(fuzzy_score("UserList", "user-list"), Some(EXACT_NAME));
+        assert_eq!(fuzzy_score("UserList", "UL"), Some(ACRONYM));
+        assert_eq!(fuzzy_score("user-list", "ul"), Some(ACRONYM));
+        assert!(fuzzy_score("UserList", "usrlst").is_some());
+        assert_eq!(fuzzy_score("UserList", "lu"), None);
+        assert_eq!(fuzzy_score("UserList", ""), None);
+
+        // Word starts and runs of letters score higher than scattered letters
+        let score = |name, query| fuzzy_score(name, query).unwrap();
+        assert!(score("UserList", "userl") > score("UserList", "usrlst"));
+        assert!(score("UserList", "list") > score("Playlist", "list"));
+        assert!(score("ListUser", "lu") > score("LongTableUser", "lu"));
+        assert!(score_key(ACRONYM, "b") < score_key(12, "a"));
+    }
+
+    #[test]
+    fn test_project_usage() {
+        let root = TempDir::new("ranking-usage");
+        fs::create_dir_all(root.join("src/views")).unwrap();
+        fs::write(root.join("src/views/List.vue"), "<template><el-table><el-table-column /><ElTableColumn /></el-table></template>").unwrap();
+        fs::write(root.join("src/views/Form.vue"), "<template><el-form><el-button /></el-form></template>").unwrap();
+
+        let mut usage = ProjectUsage::scan(&root.root());
+        assert_eq!(usage_of(usage.total(), "el-table-column"), 2);
+        assert_eq!(usage_of(usage.total(), "el-button:primary"), 1);
+        assert_eq!(usage_of(usage.total(), "el-dialog"), 0);
+
+        // A saved file replaces its own counts only
+        usage.update("src/views/List.vue", Some("<template><el-table><el-button /></el-table></template>"));
+        assert_eq!(usage_of(usage.total(), "el-table-column"), 0);
+        assert_eq!(usage_of(usage.total(), "el-button"), 2);
+        usage.update("src/views/Form.vue", None);
+        assert_eq!(usage.total(), &Usage::from([("template".to_string(), 1), ("el-table".to_string(), 1), ("el-button".to_string(), 1)]));
+    }
+}
@@ -0,0 +1,584 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+// ---- Lazy-compiled regex statics ----
+
+static RE_SCRIPT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?s)<script[^>]*>(.*?)</script>").unwrap()
+});
+
+static RE_EXPORT_DEFAULT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"export\s+default\s+(?:\w+\.extend\s*\(\s*|defineComponent\s*\(\s*)?\{").unwrap()
+});
+
+static RE_SLOT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"<slot\b([^>]*)>").unwrap()
+});
+
+static RE_SLOT_NAME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:^|\s)name=["']([\w-]+)["']"#).unwrap()
+});
+
+static RE_EMIT_CALL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:\$emit|\bemit)\(\s*['"]([\w:-]+)['"]"#).unwrap()
+});
+
+static RE_TYPED_EMIT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\(\s*\w+\s*:\s*['"]([\w:-]+)['"]"#).unwrap()
+});
+
+static RE_STRING: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"['"]([^'"]*)['"]"#).unwrap()
+});
+
+static RE_STRING_UNION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"^\s*(?:['"][^'"]*['"]\s*\|\s*)*['"][^'"]*['"]\s*$"#).unwrap()
+});
+
+static RE_VALIDATOR_VALUES: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\[([^\[\]]*)\]\s*\.\s*(?:includes|indexOf)\s*\(").unwrap()
+});
+
+static RE_PROP_TYPE_GENERIC: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"PropType<([^>]*)>").unwrap()
+});
+
+/// A prop declared by a single-file component
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SfcProp {
+    /// Name as declared (usually camelCase)
+    pub name: String,
+    /// Declared type (`String`, `String | Number`, `'small' | 'large'`), empty when unknown
+    pub prop_type: String,
+    /// Allowed values from a string literal union or an `includes` validator
+    pub values: Vec<String>,
+    /// Default value expression
+    pub default: Option<String>,
+    pub required: bool,
+}
+
+/// Public API of a single-file component, extracted from its source
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SfcComponent {
+    /// `name` option or `defineOptions({ name })`
+    pub name: Option<String>,
+    pub props: Vec<SfcProp>,
+    pub emits: Vec<String>,
+    pub slots: Vec<String>,
+}
+
+/// Extract name, props, emits and slots from a `.vue` file.
+/// Handles the Options API (`export default {}` / `defineComponent`) and `<script setup>`
+/// (`defineProps`, `withDefaults`, `defineEmits`, `defineOptions`), with runtime or type declarations.
+pub fn parse_sfc(content: &str) -> SfcComponent {
+    let script: String = RE_SCRIPT
+        .captures_iter(content)
+        .filter_map(|caps| caps.get(1))
+        .map(|m| strip_comments(m.as_str()))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut component = SfcComponent::default();
+
+    // Options API
+    if let Some(m) = RE_EXPORT_DEFAULT.find(&script) {
+        if let Some(options) = enclosed(&script, m.end() - 1) {
+            for entry in split_top_level(options, b",") {
+                let (key, value, _) = key_value(entry);
+                match key {
+                    "name" => component.name = string_literal(value),
+                    "props" => component.props = parse_runtime_props(value),
+                    "emits" => component.emits = parse_runtime_emits(value),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    // <script setup>
+    if let Some(name) = macro_argument(&script, "defineOptions")
+        .and_then(|options| option_value(options, "name"))
+        .and_then(string_literal)
+    {
+        component.name = Some(name);
+    }
+    if let Some(props) = parse_setup_props(&script) {
+        component.props = props;
+    }
+    if let Some(emits) = parse_setup_emits(&script) {
+        component.emits = emits;
+    }
+
+    // emit('x') / $emit('x') calls anywhere
+    for caps in RE_EMIT_CALL.captures_iter(content) {
+        push_unique(&mut component.emits, &caps[1]);
+    }
+
+    // Slots declared in the template
+    if let (Some(start), Some(end)) = (content.find("<template"), content.rfind("</template>")) {
+        if start < end {
+            for caps in RE_SLOT.captures_iter(&content[start..end]) {
+                let name = RE_SLOT_NAME
+                    .captures(&caps[1])
+                    .map(|c| c[1].to_string())
+                    .unwrap_or_else(|| "default".to_string());
+                push_unique(&mut component.slots, &name);
+            }
+        }
+    }
+
+    component
+}
+
+fn push_unique(list: &mut Vec<String>, item: &str) {
+    if !list.iter().any(|existing| existing == item) {
+        list.push(item.to_string());
+    }
+}
+
+/// `props: [...]` or `props: {...}` in the Options API, or the argument of `defineProps(...)`
+fn parse_runtime_props(value: &str) -> Vec<SfcProp> {
+    let value = value.trim();
+    if value.starts_with('[') {
+        return strings_in(value)
+            .into_iter()
+            .map(|name| SfcProp { name, ..Default::default() })
+            .collect();
+    }
+    let Some(body) = object_body(value) else {
+        return Vec::new();
+    };
+
+    split_top_level(body, b",")
+        .into_iter()
+        .filter_map(|entry| {
+            let (name, value, _) = key_value(entry);
+            if name.is_empty() {
+                return None;
+            }
+            let mut prop = SfcProp { name: name.to_string(), ..Default::default() };
+            if value.starts_with('{') {
+                if let Some(t) = option_value(value, "type") {
+                    prop.prop_type = runtime_type(t);
+                    prop.values = prop_type_values(t);
+                }
+                prop.default = option_value(value, "default").map(|d| d.to_string());
+                prop.required = option_value(value, "required") == Some("true");
+                if let Some(validator) = option_value(value, "validator") {
+                    if let Some(caps) = RE_VALIDATOR_VALUES.captures(validator) {
+                        prop.values = strings_in(&caps[1]);
+                    }
+                }
+            } else {
+                prop.prop_type = runtime_type(value);
+                prop.values = prop_type_values(value);
+            }
+            Some(prop)
+        })
+        .collect()
+}
+
+/// `[String, Number]` -> `String | Number`, `Object as PropType<Foo>` -> `Foo`
+fn runtime_type(value: &str) -> String {
+    if let Some(caps) = RE_PROP_TYPE_GENERIC.captures(value) {
+        return caps[1].trim().to_string();
+    }
+    let value = value.split(" as ").next().unwrap_or(value).trim();
+    match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        Some(list) => list.split(',').map(str::trim).filter(|t| !t.is_empty()).collect::<Vec<_>>().join(" | "),
+        None => value.to_string(),
+    }
+}
+
+/// Values of `String as PropType<'a' | 'b'>`
+fn prop_type_values(value: &str) -> Vec<String> {
+    RE_PROP_TYPE_GENERIC
+        .captures(value)
+        .filter(|caps| RE_STRING_UNION.is_match(&caps[1]))
+        .map(|caps| strings_in(&caps[1]))
+        .unwrap_or_default()
+}
+
+/// Props from a TypeScript type literal body (`title?: string; size: 'small' | 'large'`)
+fn parse_type_props(body: &str) -> Vec<SfcProp> {
+    split_type_members(body)
+        .into_iter()
+        .filter_map(|entry| {
+            let (name, value, optional) = key_value(entry);
+            if name.is_empty() || value.is_empty() {
+                return None;
+            }
+            Some(SfcProp {
+                name: name.to_string(),
+                prop_type: value.to_string(),
+                values: if RE_STRING_UNION.is_match(value) { strings_in(value) } else { Vec::new() },
+                default: None,
+                required: !optional,
+            })
+        })
+        .collect()
+}
+
+fn parse_setup_props(script: &str) -> Option<Vec<SfcProp>> {
+    let idx = script.find("defineProps")?;
+    let rest = script[idx + "defineProps".len()..].trim_start();
+    let offset = script.len() - rest.len();
+
+    let mut props = if let Some(generic) = rest.strip_prefix('<') {
+        parse_type_props(type_literal(script, generic)?)
+    } else if rest.starts_with('(') {
+        parse_runtime_props(enclosed(script, offset)?.trim())
+    } else {
+        return None;
+    };
+
+    // withDefaults(defineProps<...>(), { size: 'small' })
+    if let Some(args) = macro_argument(script, "withDefaults") {
+        if let Some(defaults) = split_top_level(args, b",").get(1) {
+            let body = object_body(defaults).unwrap_or_default();
+            for entry in split_top_level(body, b",") {
+                let (name, value, _) = key_value(entry);
+                if let Some(prop) = props.iter_mut().find(|p| p.name == name) {
+                    prop.default = Some(value.to_string());
+                }
+            }
+        }
+    }
+    Some(props)
+}
+
+fn parse_setup_emits(script: &str) -> Option<Vec<String>> {
+    let idx = script.find("defineEmits")?;
+    let rest = script[idx + "defineEmits".len()..].trim_start();
+    let offset = script.len() - rest.len();
+
+    if let Some(generic) = rest.strip_prefix('<') {
+        let body = type_literal(script, generic)?;
+        // Call signatures: (e: 'change', value: string): void
+        let mut emits: Vec<String> = RE_TYPED_EMIT.captures_iter(body).map(|c| c[1].to_string()).collect();
+        if emits.is_empty() {
+            // Named tuple syntax: { change: [id: number] }
+            emits = split_type_members(body)
+                .into_iter()
+                .map(|entry| key_value(entry).0.to_string())
+                .filter(|name| !name.is_empty())
+                .collect();
+        }
+        Some(emits)
+    } else if rest.starts_with('(') {
+        Some(parse_runtime_emits(enclosed(script, offset)?.trim()))
+    } else {
+        None
+    }
+}
+
+/// `emits: ['change']` or `emits: { change: null, submit: (payload) => true }`
+fn parse_runtime_emits(value: &str) -> Vec<String> {
+    let value = value.trim();
+    if value.starts_with('[') {
+        return strings_in(value);
+    }
+    match object_body(value) {
+        Some(body) => split_top_level(body, b",")
+            .into_iter()
+            .map(|entry| key_value(entry).0.to_string())
+            .filter(|name| !name.is_empty())
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Body of the type argument `<{ ... }>` or `<Props>` (resolved to `interface Props { ... }` / `type Props = { ... }`)
+fn type_literal<'a>(script: &'a str, generic: &str) -> Option<&'a str> {
+    let generic = generic.trim_start();
+    if generic.starts_with('{') {
+        let offset = script.len() - generic.len();
+        return enclosed(script, offset);
+    }
+    let ident: String = generic.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+    if ident.is_empty() {
+        return None;
+    }
+    let decl = Regex::new(&format!(r"(?:interface\s+{0}\b[^{{]*|type\s+{0}\s*=\s*)\{{", regex::escape(&ident))).ok()?;
+    let m = decl.find(script)?;
+    enclosed(script, m.end() - 1)
+}
+
+/// Argument list of the first `name(...)` call
+fn macro_argument<'a>(script: &'a str, name: &str) -> Option<&'a str> {
+    let idx = script.find(&format!("{}(", name))?;
+    enclosed(script, idx + name.len())
+}
+
+/// Body of the object literal `value`, without its own braces. Braces of nested objects, such as
+/// those of `default: () => ({})`, are kept.
+fn object_body(value: &str) -> Option<&str> {
+    value.trim().strip_prefix('{')?.strip_suffix('}')
+}
+
+/// Value of `key` among the top-level entries of the object literal `options`
+fn option_value<'a>(options: &'a str, key: &str) -> Option<&'a str> {
+    split_top_level(object_body(options)?, b",")
+        .into_iter()
+        .map(key_value)
+        .find(|(k, _, _)| *k == key)
+        .map(|(_, value, _)| value)
+}
+
+/// Split an entry into key, value and whether the key is optional (`key?:`).
+/// Handles quoted keys (`'update:modelValue': null`) and method shorthand (`validator(v) { }`).
+fn key_value(entry: &str) -> (&str, &str, bool) {
+    let entry = entry.trim();
+    let (key, rest) = match entry.chars().next() {
+        Some(q @ ('\'' | '"')) => match entry[1..].find(q) {
+            Some(end) => (&entry[1..end + 1], &entry[end + 2..]),
+            None => return ("", "", false),
+        },
+        _ => {
+            let end = entry
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$' || c == '-'))
+                .unwrap_or(entry.len());
+            (&entry[..end], &entry[end..])
+        }
+    };
+    let rest = rest.trim_start();
+    let (optional, rest) = match rest.strip_prefix('?') {
+        Some(r) => (true, r.trim_start()),
+        None => (false, rest),
+    };
+    match rest.strip_prefix(':') {
+        Some(value) => (key, value.trim(), optional),
+        None => (key, rest, optional),
+    }
+}
+
+fn string_literal(value: &str) -> Option<String> {
+    let value = value.trim();
+    let quote = value.chars().next().filter(|c| matches!(c, '\'' | '"' | '`'))?;
+    value.strip_prefix(quote)?.strip_suffix(quote).map(|s| s.to_string())
+}
+
+fn strings_in(text: &str) -> Vec<String> {
+    RE_STRING.captures_iter(text).map(|c| c[1].to_string()).collect()
+}
+
+/// Content between the bracket at byte `open` and its matching close. String literals are skipped.
+fn enclosed(text: &str, open: usize) -> Option<&str> {
+    let bytes = text.as_bytes();
+    let (open_ch, close_ch) = match bytes.get(open)? {
+        b'{' => (b'{', b'}'),
+        b'[' => (b'[', b']'),
+        b'(' => (b'(', b')'),
+        _ => return None,
+    };
+    let mut depth = 0;
+    let mut quote: Option<u8> = None;
+    let mut i = open;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if let Some(q) = quote {
+            if b == b'\\' {
+                i += 1;
+            } else if b == q {
+                quote = None;
+            }
+        } else if matches!(b, b'\'' | b'"' | b'`') {
+            quote = Some(b);
+        } else if b == open_ch {
+            depth += 1;
+        } else if b == close_ch {
+            depth -= 1;
+            if depth == 0 {
+                return Some(&text[open + 1..i]);
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Split on any of `separators` outside brackets and string literals; empty entries are dropped
+fn split_top_level<'a>(text: &'a str, separators: &[u8]) -> Vec<&'a str> {
+    split_nested(text, separators, false)
+}
+
+/// Split TypeScript type members, where `<...>` generics also nest (`Record<string, any>`)
+fn split_type_members(text: &str) -> Vec<&str> {
+    split_nested(text, b",;\n", true)
+}
+
+fn split_nested<'a>(text: &'a str, separators: &[u8], angle_brackets: bool) -> Vec<&'a str> {
+    let bytes = text.as_bytes();
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<u8> = None;
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if let Some(q) = quote {
+            if b == b'\\' {
+                i += 1;
+            } else if b == q {
+                quote = None;
+            }
+        } else {
+            match b {
+                b'\'' | b'"' | b'`' => quote = Some(b),
+                b'{' | b'[' | b'(' => depth += 1,
+                b'}' | b']' | b')' => depth -= 1,
+                b'<' if angle_brackets => depth += 1,
+                // `=>` is not a closing bracket
+                b'>' if angle_brackets && i > 0 && bytes[i - 1] != b'=' => depth -= 1,
+                _ if depth == 0 && separators.contains(&b) => {
+                    parts.push(&text[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+    parts.push(&text[start..]);
+    parts.into_iter().map(str::trim).filter(|p| !p.is_empty()).collect()
+}
+
+/// Remove `//` and `/* */` comments, leaving string literals intact
+fn strip_comments(script: &str) -> String {
+    let bytes = script.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut quote: Option<u8> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if let Some(q) = quote {
+            result.push(b);
+            if b == b'\\' && i + 1 < bytes.len() {
+                result.push(bytes[i + 1]);
+                i += 1;
+            } else if b == q {
+                quote = None;
+            }
+        } else if b == b'/' && bytes.get(i + 1) == Some(&b'/') {
+            while i < bytes.len() && bytes[i] != b'\n' {
+                i += 1;
+            }
+            continue;
+        } else if b == b'/' && bytes.get(i + 1) == Some(&b'*') {
+            i += 2;
+            while i + 1 < bytes.len() && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                i += 1;
+            }
+            i += 2;
+            continue;
+        } else {
+            if matches!(b, b'\'' | b'"' | b'`') {
+                quote = Some(b);
+            }
+            result.push(b);
+        }
+        i += 1;
+    }
+    String::from_utf8(result).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_options_api() {
+        let content = r#"<template>
+  <form class="x-search-form">
+    <slot></slot>
+    <slot name="actions" :model="model"></slot>
+  </form>
+</template>
+<script>
+export default {
+  name: 'XSearchForm',
+  props: {
+    // search model
+    model: { type: Object, required: true },
+    size: {
+      type: String,
+      default: 'medium',
+      validator: (v) => ['small', 'medium', 'large'].includes(v)
+    },
+    labelWidth: [String, Number],
+    collapsed: Boolean,
+    rules: { type: Object, default: () => {}}
+  },
+  emits: ['search', 'reset'],
+  data() {
+    return { name: 'ignored' }
+  },
+  methods: {
+    submit() { this.$emit('search', this.model) },
+    toggle() { this.$emit('update:collapsed', !this.collapsed) }
+  }
+}
+</script>"#;
+        let component = parse_sfc(content);
+        assert_eq!(component.name.as_deref(), Some("XSearchForm"));
+        let names: Vec<&str> = component.props.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["model", "size", "labelWidth", "collapsed", "rules"]);
+        assert!(component.props[0].required);
+        assert_eq!(component.props[1].values, vec!["small", "medium", "large"]);
+        assert_eq!(component.props[1].default.as_deref(), Some("'medium'"));
+        assert_eq!(component.props[2].prop_type, "String | Number");
+        // The closing brace of a nested default is kept
+        assert_eq!(component.props[4].default.as_deref(), Some("() => {}"));
+        assert_eq!(component.emits, vec!["search", "reset", "update:collapsed"]);
+        assert_eq!(component.slots, vec!["default", "actions"]);
+    }
+
+    #[test]
+    fn test_parse_script_setup_types() {
+        let content = r#"<script setup lang="ts">
+defineOptions({ name: 'XCrudTable' })
+
+interface Props {
+  data: Record<string, any>[]
+  size?: 'small' | 'default' | 'large'
+  border?: boolean
+  filters?: Record<string, string>
+}
+const props = withDefaults(defineProps<Props>(), {
+  size: 'default',
+  border: true,
+  filters: () => ({})})
+const emit = defineEmits<{
+  (e: 'row-click', row: any): void
+  (e: 'update:page', page: number): void
+}>()
+</script>
+<template><div><slot name="toolbar" /></div></template>"#;
+        let component = parse_sfc(content);
+        assert_eq!(component.name.as_deref(), Some("XCrudTable"));
+        assert_eq!(component.props.len(), 4);
+        assert!(component.props[0].required);
+        assert_eq!(component.props[1].values, vec!["small", "default", "large"]);
+        assert_eq!(component.props[1].default.as_deref(), Some("'default'"));
+        assert!(!component.props[2].required);
+        assert_eq!(component.props[3].default.as_deref(), Some("() => ({})"));
+        assert_eq!(component.emits, vec!["row-click", "update:page"]);
+        assert_eq!(component.slots, vec!["toolbar"]);
+    }
+
+    #[test]
+    fn test_parse_script_setup_runtime() {
+        let content = r#"<script setup>
+const props = defineProps({
+  title: String,
+  type: { type: String as PropType<'primary' | 'danger'>, default: 'primary' }
+})
+const emit = defineEmits(['close'])
+</script>"#;
+        let component = parse_sfc(content);
+        assert_eq!(component.name, None);
+        assert_eq!(component.props[1].prop_type, "'primary' | 'danger'");
+        assert_eq!(component.props[1].values, vec!["primary", "danger"]);
+        assert_eq!(component.emits, vec!["close"]);
+    }
+}
@@ -0,0 +1,41 @@
+//! Helpers shared by the tests
+
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+/// Temporary directory removed on drop. The name is unique per process and call, so tests running
+/// in parallel or left over from an interrupted run do not see each other's files.
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        let unique = format!("vue-helper-{}-{}-{}", name, std::process::id(), NEXT_DIR.fetch_add(1, Ordering::Relaxed));
+        let path = std::env::temp_dir().join(unique);
+        std::fs::create_dir_all(&path).unwrap();
+        TempDir { path }
+    }
+
+    /// The path as the string the APIs take
+    pub fn root(&self) -> String {
+        self.path.to_string_lossy().to_string()
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
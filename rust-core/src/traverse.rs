@@ -1,4 +1,3 @@
-use napi::bindgen_prelude::*;
 use std::fs;
 use std::path::Path;
 
@@ -7,9 +6,15 @@ use std::path::Path;
 #[derive(Clone, Debug)]
 pub struct VueFile {
     pub name: String,
+    /// Import path, with the alias in place of the alias directory: `@/components/UserList.vue`
     pub path: String,
+    /// Path relative to the project root: `src/components/UserList.vue`
+    pub relative_path: String,
 }
 
+/// Directories of the project root holding dependencies and build output
+pub const IGNORED_DIRS: [&str; 3] = ["node_modules", "dist", "build"];
+
 /// Search for files in the project directory
 pub fn search(
     root_path: &str,
@@ -18,6 +23,19 @@ pub fn search(
     use_prefix: bool,
     prefix_alias: &str,
     prefix_path: &str,
+) -> Vec<VueFile> {
+    search_in(root_path, &IGNORED_DIRS, poster, search_name, use_prefix, prefix_alias, prefix_path)
+}
+
+/// Search for files in a directory, skipping the `ignore` directories at its top level
+pub fn search_in(
+    root_path: &str,
+    ignore: &[&str],
+    poster: &str,
+    search_name: &str,
+    use_prefix: bool,
+    prefix_alias: &str,
+    prefix_path: &str,
 ) -> Vec<VueFile> {
     let mut files = Vec::new();
 
@@ -25,8 +43,6 @@ pub fn search(
         return files;
     }
 
-    let ignore = ["node_modules", "dist", "build"];
-
     let root = Path::new(root_path);
     if !root.exists() || !root.is_dir() {
         return files;
@@ -90,22 +106,12 @@ fn traverse_add(
         return;
     }
 
-    let poster_pattern = if poster.is_empty() {
-        r"-?(.*)\.\w*$"
-    } else {
-        "" // handled below
-    };
-
     let name = if !poster.is_empty() {
         let suffix = poster;
         if let Some(idx) = root_path.rfind(suffix) {
             let base = &root_path[..idx];
             // Remove leading dash if present
-            if base.starts_with('-') {
-                base[1..].to_string()
-            } else {
-                base.to_string()
-            }
+            base.strip_prefix('-').unwrap_or(base).to_string()
         } else {
             root_path.to_string()
         }
@@ -113,18 +119,15 @@ fn traverse_add(
         // Remove extension
         if let Some(dot_idx) = root_path.rfind('.') {
             let base = &root_path[..dot_idx];
-            if base.starts_with('-') {
-                base[1..].to_string()
-            } else {
-                base.to_string()
-            }
+            base.strip_prefix('-').unwrap_or(base).to_string()
         } else {
             root_path.to_string()
         }
     };
 
     if search.is_empty() || dir.contains(search) {
-        let mut file_path = dir.replace('\\', "/");
+        let relative_path = dir.replace('\\', "/");
+        let mut file_path = relative_path.clone();
         if !prefix_alias.is_empty() && !prefix_path.is_empty() {
             if let Some(stripped) = file_path.strip_prefix(prefix_path) {
                 file_path = format!("{}{}", prefix_alias, stripped);
@@ -134,18 +137,19 @@ fn traverse_add(
         files.push(VueFile {
             name: name.clone(),
             path: file_path.clone(),
+            relative_path: relative_path.clone(),
         });
 
         // Also add index-based name
         if name == "index" {
-            let normalized = dir.replace('\\', "/");
             // Extract parent directory name
-            let parts: Vec<&str> = normalized.split('/').collect();
+            let parts: Vec<&str> = relative_path.split('/').collect();
             if parts.len() >= 2 {
                 let parent_name = parts[parts.len() - 2].to_string();
                 files.push(VueFile {
                     name: parent_name,
                     path: file_path,
+                    relative_path,
                 });
             }
         }
@@ -212,6 +216,10 @@ mod tests {
         assert_eq!(files.len(), 1);
         assert_eq!(files[0].name, "MyComponent");
         assert_eq!(files[0].path, "src/components/MyComponent.vue");
+
+        traverse_add("MyComponent.vue", "src/components/MyComponent.vue", &mut files, "@", "src", ".vue", "");
+        assert_eq!(files[1].path, "@/components/MyComponent.vue");
+        assert_eq!(files[1].relative_path, "src/components/MyComponent.vue");
     }
 
     #[test]
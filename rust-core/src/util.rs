@@ -8,6 +8,22 @@ pub struct WordResult {
     pub start_text: String,
 }
 
+/// Convert camelCase / PascalCase to kebab-case (`XSearchForm` -> `x-search-form`)
+pub fn to_kebab_case(s: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                result.push('-');
+            }
+            result.push(c.to_lowercase().next().unwrap());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
 /// Handle Windows root path (remove leading slash on Windows)
 pub fn win_root_path_handle(page_path: &str) -> String {
     if page_path.is_empty() {
@@ -20,18 +36,20 @@ pub fn win_root_path_handle(page_path: &str) -> String {
     }
 }
 
-/// Get relative path between two paths
+/// Path of `dist` relative to the file `src`, as written in an import: `./Foo.vue`, `../components/Foo.vue`
 pub fn get_relative_path(src: &str, dist: &str) -> String {
     let src_handled = win_root_path_handle(src);
     let src_path = Path::new(&src_handled);
     let dist_path = Path::new(dist);
 
     if let Some(rel) = pathdiff_relative(dist_path, src_path) {
-        let mut result = rel.replace('\\', "/");
-        if result.starts_with("../") {
-            result = result[1..].to_string();
+        let result = rel.replace('\\', "/");
+        // The first `../` leaves the file `src` for its directory
+        match result.strip_prefix("../") {
+            Some(rest) if rest.starts_with("../") => rest.to_string(),
+            Some(rest) => format!("./{}", rest),
+            None => result,
         }
-        result
     } else {
         dist.replace('\\', "/")
     }
@@ -58,12 +76,7 @@ fn pathdiff_relative(to: &Path, from: &Path) -> Option<String> {
     for _ in common..from_parts.len() {
         result.push_str("../");
     }
-    for i in common..to_parts.len() {
-        if i > common {
-            result.push('/');
-        }
-        result.push_str(to_parts[i]);
-    }
+    result.push_str(&to_parts[common..].join("/"));
 
     if result.is_empty() {
         result = ".".to_string();
@@ -72,6 +85,23 @@ fn pathdiff_relative(to: &Path, from: &Path) -> Option<String> {
     Some(result)
 }
 
+/// Byte offset in `text` of the column `character`, counted in UTF-16 code units as editors do
+pub fn byte_offset(text: &str, character: u32) -> usize {
+    let mut units = 0;
+    for (offset, c) in text.char_indices() {
+        if units >= character as usize {
+            return offset;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+/// Column of the byte offset `offset` in `text`, in UTF-16 code units
+pub fn utf16_column(text: &str, offset: usize) -> u32 {
+    text[..offset].encode_utf16().count() as u32
+}
+
 /// Get current word at cursor position
 pub fn get_current_word(text: &str, character: usize) -> String {
     let chars: Vec<char> = text.chars().collect();
@@ -150,6 +180,21 @@ mod tests {
         assert_eq!(get_current_word("  hello", 7), "hello");
     }
 
+    #[test]
+    fn test_get_relative_path() {
+        assert_eq!(get_relative_path("/app/src/views/Home.vue", "/app/src/views/List.vue"), "./List.vue");
+        assert_eq!(get_relative_path("/app/src/views/Home.vue", "/app/src/views/user/Form.vue"), "./user/Form.vue");
+        assert_eq!(get_relative_path("/app/src/views/Home.vue", "/app/src/components/Foo.vue"), "../components/Foo.vue");
+    }
+
+    #[test]
+    fn test_byte_offset() {
+        let text = "'😀/a.vue'";
+        assert_eq!(byte_offset(text, 3), 5);
+        assert_eq!(utf16_column(text, 5), 3);
+        assert_eq!(byte_offset(text, 40), text.len());
+    }
+
     #[test]
     fn test_win_root_path_handle() {
         if cfg!(windows) {
@@ -161,7 +206,7 @@ mod tests {
 
     #[test]
     fn test_get_word() {
-        let delimiters: Vec<String> = vec![" ", "<", ">", "\""].iter().map(|s| s.to_string()).collect();
+        let delimiters: Vec<String> = [" ", "<", ">", "\""].iter().map(|s| s.to_string()).collect();
         let result = get_word("  <el-button type=\"primary\">", 5, &delimiters);
         assert_eq!(result.select_text, "el-button");
         assert_eq!(result.start_text, "<");
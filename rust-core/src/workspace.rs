@@ -0,0 +1,482 @@
+use crate::completion::{self, CompletionSuggestion, ImportPathOptions};
+use crate::framework_data::{self, custom, detect, locale::Locale, provider::FrameworkProvider, scan, FrameworkCache};
+use crate::ranking::ProjectUsage;
+use crate::traverse::{self, VueFile};
+use crate::{assist, hover, path_completion};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Settings of one workspace root, mirroring the `vue-helper.*` configuration
+#[napi(object)]
+#[derive(Clone, Debug, Default)]
+pub struct WorkspaceConfig {
+    /// Editor indentation for snippets, two spaces by default
+    pub tab_size: Option<String>,
+    /// Documentation language (`zh-cn`, `en`), Chinese by default
+    pub locale: Option<String>,
+    /// Frameworks to use instead of the ones detected from package.json
+    pub frameworks: Option<Vec<String>>,
+    /// Suffix of indexed component files, `.vue` by default
+    pub component_poster: Option<String>,
+    /// Import alias of `component_prefix`, `@` by default
+    pub alias: Option<String>,
+    /// Directory the alias points to, `src` by default
+    pub component_prefix: Option<String>,
+    /// In-house component libraries scanned from source
+    pub component_libraries: Option<Vec<ComponentLibrary>>,
+    /// How import suggestions write paths. The alias defaults to `alias` and `component_prefix`.
+    pub import_paths: Option<ImportPathOptions>,
+}
+
+/// A component library directory, relative to the workspace root
+#[napi(object)]
+#[derive(Clone, Debug)]
+pub struct ComponentLibrary {
+    pub name: String,
+    pub path: String,
+    pub prefix: Option<String>,
+}
+
+/// Session for one workspace root. It owns the detected frameworks, the settings, the component
+/// file index and the framework data, so roots using different libraries do not share a cache.
+/// The project component definitions and the scanned libraries are providers of the session, so
+/// sessions are independent of each other and of the functions that take the frameworks per call.
+#[napi]
+pub struct Workspace {
+    root: String,
+    config: WorkspaceConfig,
+    frameworks: Vec<String>,
+    /// Providers of the project component definitions and the scanned libraries
+    providers: Vec<Arc<dyn FrameworkProvider>>,
+    files: Vec<VueFile>,
+    /// Images, media and fonts, for `src` and `url()` completions
+    assets: Vec<VueFile>,
+    /// How often the project uses each tag, for ranking
+    usage: ProjectUsage,
+    messages: Vec<String>,
+    cache: Option<Arc<FrameworkCache>>,
+}
+
+#[napi]
+impl Workspace {
+    /// Open a session: detect the frameworks and index the component files of `root`
+    #[napi(constructor)]
+    pub fn new(root: String, config: Option<WorkspaceConfig>) -> Self {
+        let mut workspace = Workspace {
+            root,
+            config: config.unwrap_or_default(),
+            frameworks: Vec::new(),
+            providers: Vec::new(),
+            files: Vec::new(),
+            assets: Vec::new(),
+            usage: ProjectUsage::default(),
+            messages: Vec::new(),
+            cache: None,
+        };
+        workspace.refresh();
+        workspace
+    }
+
+    #[napi(getter)]
+    pub fn root(&self) -> String {
+        self.root.clone()
+    }
+
+    /// Framework specs used for completion and hover (`element-ui@2.15.14`)
+    #[napi(getter)]
+    pub fn frameworks(&self) -> Vec<String> {
+        self.frameworks.clone()
+    }
+
+    /// Indexed component files
+    #[napi(getter)]
+    pub fn files(&self) -> Vec<VueFile> {
+        self.files.clone()
+    }
+
+    /// Messages of the last refresh
+    #[napi(getter)]
+    pub fn messages(&self) -> Vec<String> {
+        self.messages.clone()
+    }
+
+    /// Replace the settings, then detect and index again
+    #[napi]
+    pub fn set_config(&mut self, config: WorkspaceConfig) -> Vec<String> {
+        self.config = config;
+        self.refresh()
+    }
+
+    /// Detect the frameworks from package.json (or take them from the settings), load the project
+    /// component definitions, scan the configured libraries and index the component files.
+    /// Returns one message per problem or scanned library, for the output channel.
+    #[napi]
+    pub fn refresh(&mut self) -> Vec<String> {
+        let mut messages = Vec::new();
+        self.frameworks = match &self.config.frameworks {
+            Some(frameworks) if !frameworks.is_empty() => frameworks.clone(),
+            _ => self.detect_frameworks(),
+        };
+
+        self.providers.clear();
+        let (custom, result) = custom::read_custom_components(&self.root);
+        messages.extend(result.errors.iter().map(|error| format!("custom components: {}", error)));
+        if let Some(custom) = custom {
+            self.add_provider(Arc::new(custom));
+        }
+
+        for library in self.config.component_libraries.clone().unwrap_or_default() {
+            let dir = Path::new(&self.root).join(&library.path);
+            match scan::scan_library(&library.name, &dir.to_string_lossy(), library.prefix.as_deref()) {
+                Ok((provider, result)) => {
+                    messages.push(format!("component library {}: {} components", result.framework, result.tags.len()));
+                    if !self.add_provider(Arc::new(provider)) {
+                        messages.push(format!("component library {}: the name is already used in this workspace", library.name));
+                    }
+                }
+                Err(e) => messages.push(format!("component library {}: {}", library.name, e)),
+            }
+        }
+
+        self.cache = None;
+        self.reindex_files();
+        self.messages = messages.clone();
+        messages
+    }
+
+    /// Index the component and asset files again and count the tags they use, after files were
+    /// created or deleted
+    #[napi]
+    pub fn reindex_files(&mut self) {
+        let poster = self.config.component_poster.as_deref().unwrap_or(".vue");
+        let alias = self.config.alias.as_deref().unwrap_or("@");
+        let prefix = self.config.component_prefix.as_deref().unwrap_or("src");
+        self.files = traverse::search(&self.root, poster, "", true, alias, prefix);
+        self.reindex_assets();
+        self.usage = ProjectUsage::scan(&self.root);
+    }
+
+    /// Index the asset files again, after images, media or fonts were created or deleted
+    #[napi]
+    pub fn reindex_assets(&mut self) {
+        let alias = self.config.alias.as_deref().unwrap_or("@");
+        let prefix = self.config.component_prefix.as_deref().unwrap_or("src");
+        self.assets = path_completion::find_assets(&self.root, alias, prefix);
+    }
+
+    /// Count the tags of a saved `.vue` file again. The file index is kept: only files counted by
+    /// the last `reindex_files` are updated, so files in `node_modules` or build output are ignored.
+    #[napi]
+    pub fn update_file(&mut self, path: String) {
+        let Ok(relative) = Path::new(&path).strip_prefix(&self.root) else {
+            return;
+        };
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        if self.usage.contains(&relative) {
+            let content = fs::read_to_string(&path).ok();
+            self.usage.update(&relative, content.as_deref());
+        }
+    }
+
+    /// Release the framework data and the file index. A disposed session has no frameworks and
+    /// returns no completions until `refresh` is called.
+    #[napi]
+    pub fn dispose(&mut self) {
+        self.frameworks.clear();
+        self.providers.clear();
+        self.files.clear();
+        self.assets.clear();
+        self.usage.clear();
+        self.messages.clear();
+        self.cache = None;
+    }
+
+    #[napi]
+    pub fn tag_completions(
+        &mut self,
+        use_vue_snippets: bool,
+        text_before: Option<String>,
+        prefix: Option<String>,
+        max_count: Option<u32>,
+    ) -> Vec<CompletionSuggestion> {
+        let parent = text_before.as_deref().and_then(completion::find_parent_tag);
+        let prefix = prefix.as_deref().unwrap_or("");
+        let data = self.data();
+        completion::get_tag_suggestions(&data, self.tab_size(), use_vue_snippets, parent.as_deref(), self.usage.total(), prefix, completion::max_count(max_count))
+    }
+
+    #[napi]
+    pub fn js_tag_completions(&mut self, use_vue_snippets: bool) -> Vec<CompletionSuggestion> {
+        completion::get_js_tag_suggestions(&self.data(), self.tab_size(), use_vue_snippets)
+    }
+
+    #[napi]
+    pub fn attr_completions(&mut self, tag: String, attr_type: String, prefix: Option<String>, max_count: Option<u32>) -> Vec<CompletionSuggestion> {
+        let prefix = prefix.as_deref().unwrap_or("");
+        completion::get_attr_suggestions(&tag, &self.data(), &attr_type, prefix, completion::max_count(max_count))
+    }
+
+    #[napi]
+    pub fn attr_value_completions(&mut self, tag: String, attr: String) -> Vec<CompletionSuggestion> {
+        completion::get_attr_value_suggestions(&tag, &attr, &self.data())
+    }
+
+    #[napi]
+    pub fn element_tag_labels(
+        &mut self,
+        extension_name: String,
+        text_before: Option<String>,
+        prefix: Option<String>,
+        max_count: Option<u32>,
+    ) -> Vec<CompletionSuggestion> {
+        let parent = text_before.as_deref().and_then(completion::find_parent_tag);
+        let prefix = prefix.as_deref().unwrap_or("");
+        let data = self.data();
+        completion::get_element_tag_label_suggestions(&data, &extension_name, parent.as_deref(), self.usage.total(), prefix, completion::max_count(max_count))
+    }
+
+    #[napi]
+    pub fn slot_completions(&mut self, text_before: String) -> Vec<CompletionSuggestion> {
+        completion::get_slot_suggestions(&text_before, &self.data())
+    }
+
+    #[napi]
+    pub fn event_handler_edit(&mut self, content: String, tag: String, event: String) -> Option<assist::EditOperation> {
+        completion::get_event_handler_edit(&content, &tag, &event, &self.data(), self.tab_size())
+    }
+
+    #[napi]
+    pub fn ref_method_completions(&mut self, content: String, text_before: String) -> Vec<CompletionSuggestion> {
+        completion::get_ref_method_suggestions(&content, &text_before, &self.data())
+    }
+
+    #[napi]
+    pub fn hover_at(&mut self, text_before: String, word: String) -> Option<String> {
+        hover::provide_hover_at(&text_before, &word, &self.data())
+    }
+
+    /// Import suggestions from the indexed component files, importing into `document_path`
+    #[napi]
+    pub fn import_suggestions(&self, search_text: String, document_path: String) -> Vec<CompletionSuggestion> {
+        completion::get_import_suggestion_items(&search_text, &self.files, &document_path, &self.root, &self.import_options())
+    }
+
+    /// Tags of the indexed components for the document at `document_path`, the import specifier
+    /// as their detail
+    #[napi]
+    pub fn component_tag_completions(&self, document_path: String, prefix: Option<String>, max_count: Option<u32>) -> Vec<CompletionSuggestion> {
+        let prefix = prefix.as_deref().unwrap_or("");
+        completion::get_component_tag_suggestions(&self.files, &document_path, &self.root, &self.import_options(), self.usage.total(), prefix, completion::max_count(max_count))
+    }
+
+    /// Edits importing the component of `tag` from `specifier` into the document `content` and
+    /// registering it
+    #[napi]
+    pub fn component_import_edits(&self, content: String, tag: String, specifier: String) -> Vec<assist::EditOperation> {
+        completion::get_component_import_edits(&content, &tag, &specifier, self.tab_size())
+    }
+
+    /// Paths for the module specifier at `character` of `line_text`, null outside of one
+    #[napi]
+    pub fn module_path_completions(
+        &self,
+        line_text: String,
+        line: u32,
+        character: u32,
+        document_path: String,
+    ) -> Option<Vec<CompletionSuggestion>> {
+        path_completion::get_module_path_suggestions(&line_text, line, character, &document_path, &self.root, &self.import_options())
+    }
+
+    /// Asset paths for the `src` or `url()` at `character` of `line_text`, null outside of one
+    #[napi]
+    pub fn asset_path_completions(
+        &self,
+        line_text: String,
+        line: u32,
+        character: u32,
+        document_path: String,
+    ) -> Option<Vec<CompletionSuggestion>> {
+        path_completion::get_asset_path_suggestions(&line_text, line, character, &document_path, &self.root, &self.assets, &self.import_options())
+    }
+
+    /// Asset references of the document `content` at `document_path` that point to no file
+    #[napi]
+    pub fn missing_assets(&self, content: String, document_path: String) -> Vec<path_completion::MissingAsset> {
+        path_completion::find_missing_assets(&content, &document_path, &self.root, &self.import_options())
+    }
+}
+
+impl Workspace {
+    fn detect_frameworks(&self) -> Vec<String> {
+        let package_json = Path::new(&self.root).join("package.json");
+        match fs::read_to_string(package_json) {
+            Ok(content) => detect::detect_frameworks(&content, Some(&self.root)).into_iter().map(|f| f.spec).collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    fn add_framework(&mut self, framework: String) {
+        if !self.frameworks.contains(&framework) {
+            self.frameworks.push(framework);
+        }
+    }
+
+    /// Use a provider of the session. Returns false when the session already has one of that name.
+    fn add_provider(&mut self, provider: Arc<dyn FrameworkProvider>) -> bool {
+        if self.providers.iter().any(|p| p.name() == provider.name()) {
+            return false;
+        }
+        self.add_framework(provider.name().to_string());
+        self.providers.push(provider);
+        true
+    }
+
+    /// Import path settings, the alias defaulting to the one of the file index
+    fn import_options(&self) -> ImportPathOptions {
+        let mut options = self.config.import_paths.clone().unwrap_or_default();
+        options.alias = options.alias.or_else(|| self.config.alias.clone());
+        options.alias_path = options.alias_path.or_else(|| self.config.component_prefix.clone());
+        options
+    }
+
+    fn tab_size(&self) -> &str {
+        self.config.tab_size.as_deref().unwrap_or("  ")
+    }
+
+    /// The session's framework data, rebuilt when the locale changed since it was built
+    fn data(&mut self) -> Arc<FrameworkCache> {
+        let locale = Locale::parse(self.config.locale.as_deref());
+        if let Some(cache) = &self.cache {
+            if cache.locale == locale {
+                return Arc::clone(cache);
+            }
+        }
+        let cache = Arc::new(framework_data::build_cache_with(&self.frameworks, locale, &self.providers));
+        self.cache = Some(Arc::clone(&cache));
+        cache
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    fn project(name: &str, dependencies: &str) -> TempDir {
+        let root = TempDir::new(name);
+        fs::create_dir_all(root.join("src/components")).unwrap();
+        fs::write(root.join("package.json"), format!(r#"{{ "dependencies": {{ {} }} }}"#, dependencies)).unwrap();
+        fs::write(root.join("src/components/UserList.vue"), "<template><div /></template>").unwrap();
+        root
+    }
+
+    fn ui_project(name: &str) -> TempDir {
+        project(name, r#""element-ui": "^2.15.0""#)
+    }
+
+    fn plus_project(name: &str) -> TempDir {
+        project(name, r#""element-plus": "^2.4.0""#)
+    }
+
+    #[test]
+    fn test_detection() {
+        let root = ui_project("workspace-detection");
+        let ui = Workspace::new(root.root(), None);
+        assert_eq!(ui.frameworks(), vec!["element-ui@2.15.0"]);
+        assert_eq!(ui.files()[0].path, "@/components/UserList.vue");
+    }
+
+    #[test]
+    fn test_sessions_keep_their_data() {
+        let ui_root = ui_project("workspace-ui");
+        let plus_root = plus_project("workspace-plus");
+        let mut ui = Workspace::new(ui_root.root(), None);
+        let mut plus = Workspace::new(plus_root.root(), None);
+
+        let has_sub_menu = |items: Vec<CompletionSuggestion>| items.iter().any(|s| s.label == "el-sub-menu");
+        assert!(!has_sub_menu(ui.tag_completions(false, None, None, None)));
+        assert!(has_sub_menu(plus.tag_completions(false, None, None, None)));
+        let ui_data = ui.data();
+        plus.attr_completions("el-button".to_string(), String::new(), None, None);
+        assert!(Arc::ptr_eq(&ui_data, &ui.data()), "another session should not evict the cache");
+    }
+
+    #[test]
+    fn test_locale() {
+        let root = plus_project("workspace-locale");
+        let mut plus = Workspace::new(root.root(), Some(WorkspaceConfig { locale: Some("en".to_string()), ..Default::default() }));
+        assert_eq!(plus.data().locale, Locale::En);
+    }
+
+    #[test]
+    fn test_import_suggestions() {
+        let root = plus_project("workspace-imports");
+        let plus = Workspace::new(root.root(), None);
+        let document = root.join("src/views/Home.vue");
+        let imports = plus.import_suggestions("import userlist".to_string(), document.to_string_lossy().to_string());
+        assert_eq!(imports[0].insert_text, "${1:UserList} from '@/components/UserList.vue'");
+    }
+
+    #[test]
+    fn test_settings_override_detection() {
+        let root = ui_project("workspace-settings");
+        let mut ui = Workspace::new(root.root(), None);
+        ui.set_config(WorkspaceConfig { frameworks: Some(vec!["vant".to_string()]), ..Default::default() });
+        assert_eq!(ui.frameworks(), vec!["vant"]);
+        assert!(ui.tag_completions(false, None, None, None).iter().any(|s| s.label.starts_with("van-")));
+    }
+
+    #[test]
+    fn test_update_file() {
+        let root = plus_project("workspace-update");
+        let mut plus = Workspace::new(root.root(), None);
+
+        // A saved file is counted again without indexing the project
+        let user_list = root.join("src/components/UserList.vue");
+        fs::write(&user_list, "<template><el-tag /><el-tag /></template>").unwrap();
+        plus.update_file(user_list.to_string_lossy().to_string());
+        assert_eq!(plus.usage.total().get("el-tag"), Some(&2));
+        assert_eq!(plus.usage.total().get("div"), None);
+        plus.update_file(root.join("node_modules/x/X.vue").to_string_lossy().to_string());
+        assert_eq!(plus.files().len(), 1);
+    }
+
+    #[test]
+    fn test_dispose() {
+        let ui_root = ui_project("workspace-dispose");
+        let plus_root = plus_project("workspace-kept");
+        let mut ui = Workspace::new(ui_root.root(), None);
+        let mut plus = Workspace::new(plus_root.root(), None);
+
+        ui.dispose();
+        assert!(ui.frameworks().is_empty());
+        assert!(ui.tag_completions(false, None, None, None).is_empty());
+        assert!(ui.import_suggestions(String::new(), String::new()).is_empty());
+        // Other sessions are not affected
+        assert!(plus.hover_at("<".to_string(), "el-button".to_string()).is_some());
+    }
+
+    #[test]
+    fn test_project_components_per_session() {
+        let components = |tag: &str| format!(r#"{{ "components": {{ "{}": {{ "snippet": "<{}></{}>" }} }} }}"#, tag, tag, tag);
+        let first_root = project("workspace-first", "");
+        let second_root = project("workspace-second", "");
+        for (root, tag) in [(&first_root, "x-first"), (&second_root, "x-second")] {
+            fs::create_dir_all(root.join(".vue-helper")).unwrap();
+            fs::write(root.join(custom::CUSTOM_COMPONENTS_FILE), components(tag)).unwrap();
+        }
+        let mut first = Workspace::new(first_root.root(), None);
+        let mut second = Workspace::new(second_root.root(), None);
+
+        // Both definitions use the default name `project` without replacing each other
+        let labels = |workspace: &mut Workspace| workspace.data().tags().into_keys().collect::<Vec<_>>();
+        assert_eq!(labels(&mut first), vec!["x-first"]);
+        assert_eq!(labels(&mut second), vec!["x-second"]);
+
+        first.dispose();
+        assert!(labels(&mut first).is_empty());
+        assert_eq!(labels(&mut second), vec!["x-second"]);
+    }
+}
@@ -1,4 +1,4 @@
-import { ExtensionContext, workspace, commands, window, StatusBarAlignment, TextDocument } from 'vscode'
+import { ExtensionContext, workspace, commands, window, env, StatusBarAlignment, TextDocument, Uri } from 'vscode'
 import * as native from './native'
 import * as path from 'path'
 import * as fs from 'fs'
@@ -17,6 +17,16 @@ function debounce<T extends (...args: any[]) => void>(fn: T, delay: number): T {
   }) as unknown as T
 }
 
+// Changes of one workspace root waiting for the debounced update
+interface FileChanges {
+  // Components were created or deleted
+  components: boolean
+  // Images, media or fonts were created or deleted
+  assets: boolean
+  // Saved components
+  saved: Set<string>
+}
+
 export default class ExplorerProvider {
   public name: string = 'vue-helper'
   public context: ExtensionContext
@@ -25,13 +35,20 @@ export default class ExplorerProvider {
   public prefix: Prefix = { alias: '@', path: 'src' }
   public config: any
   public tabSize: string = '  '
+  // Documentation language: `vue-helper.locale`, otherwise the VS Code display language
+  public locale: string = 'zh-cn'
   public vueFiles: native.VueFile[] = []
   public vueFilesByNormalizedName: Map<string, native.VueFile> = new Map()
   public frameworks: string[] = []
+  // One session per workspace root, kept while the folder is open
+  public sessions: Map<string, native.Workspace> = new Map()
+  public session!: native.Workspace
   public isTs: boolean = false
   public inits: { init: () => void }[] = []
+  // File changes since the last reindex, by workspace root
+  private changes: Map<string, FileChanges> = new Map()
 
-  private debouncedSearchVueFiles = debounce(() => this.searchVueFiles(), 300)
+  private debouncedApplyChanges = debounce(() => this.applyChanges(), 300)
   private debouncedOpenDocument = debounce((e: TextDocument) => this._openDocumentCore(e), 300)
 
   public setContext(name: string, value: boolean) {
@@ -65,6 +82,8 @@ export default class ExplorerProvider {
 
     this.config = workspace.getConfiguration('vue-helper')
     this.tabSize = this.getTabSize()
+    const localeConfig = this.config.get('locale')
+    this.locale = typeof localeConfig === 'string' && localeConfig ? localeConfig : env.language
 
     const aliasConfig = this.config.get('alias')
     this.prefix.alias = typeof aliasConfig === 'string' ? aliasConfig : '@'
@@ -74,8 +93,7 @@ export default class ExplorerProvider {
     const tsconfigPath = path.join(this.projectRootPath, 'tsconfig.json')
     this.isTs = fs.existsSync(tsconfigPath)
 
-    this.detectFrameworks()
-    this.searchVueFiles() // Initial call is synchronous (not debounced)
+    this.detectFrameworks() // Initial call is synchronous (not debounced)
     this.watchFiles()
 
     const statusBar = window.createStatusBarItem(StatusBarAlignment.Right, -99999)
@@ -86,39 +104,88 @@ export default class ExplorerProvider {
     workspace.onDidOpenTextDocument((e: TextDocument) => {
       this.openDocument(e)
     })
+    workspace.onDidChangeWorkspaceFolders((e) => {
+      e.removed.forEach((folder) => this.closeSession(folder.uri.fsPath))
+    })
+    context.subscriptions.push({
+      dispose: () => Array.from(this.sessions.keys()).forEach((root) => this.closeSession(root))
+    })
   }
 
+  // Open the session of the current root, or refresh it when the root was opened before
   public detectFrameworks() {
-    try {
-      const packageJsonPath = path.join(this.projectRootPath, 'package.json')
-      outputChannel.appendLine('projectRootPath: ' + this.projectRootPath)
-      if (fs.existsSync(packageJsonPath)) {
-        const content = fs.readFileSync(packageJsonPath, 'utf8')
-        this.frameworks = native.initFrameworks(content)
-        outputChannel.appendLine('detected frameworks: ' + JSON.stringify(this.frameworks))
+    outputChannel.appendLine('projectRootPath: ' + this.projectRootPath)
+    let session = this.sessions.get(this.projectRootPath)
+    let messages: string[]
+    if (session) {
+      messages = session.setConfig(this.workspaceConfig())
+    } else {
+      session = native.openWorkspace(this.projectRootPath, this.workspaceConfig())
+      this.sessions.set(this.projectRootPath, session)
+      messages = session.messages
+    }
+    this.session = session
+    messages.forEach((message) => outputChannel.appendLine(message))
+    this.frameworks = session.frameworks
+    outputChannel.appendLine('final frameworks: ' + JSON.stringify(this.frameworks))
+    this.syncVueFiles()
+  }
+
+  // Update the sessions of the roots whose files changed, not only the active one. Created or
+  // deleted components index the root again, saved ones only count their tags again.
+  public applyChanges() {
+    const changes = Array.from(this.changes)
+    this.changes.clear()
+    for (const [root, change] of changes) {
+      const session = this.sessions.get(root)
+      if (!session) continue
+      if (change.components) {
+        session.reindexFiles()
+      } else {
+        if (change.assets) session.reindexAssets()
+        change.saved.forEach((file) => session.updateFile(file))
       }
-    } catch (_e: any) {
-      outputChannel.appendLine('detectFrameworks error: ' + _e.message)
-      this.frameworks = []
     }
+    if (changes.some(([root, change]) => root === this.projectRootPath && change.components)) {
+      this.syncVueFiles()
+    }
+  }
+
+  private fileChanged(uri: Uri, kind: 'components' | 'assets' | 'saved') {
+    const folder = workspace.getWorkspaceFolder(uri)
+    if (!folder || !this.sessions.has(folder.uri.fsPath)) return
+    let change = this.changes.get(folder.uri.fsPath)
+    if (!change) {
+      change = { components: false, assets: false, saved: new Set() }
+      this.changes.set(folder.uri.fsPath, change)
+    }
+    if (kind === 'saved') {
+      change.saved.add(uri.fsPath)
+    } else {
+      change[kind] = true
+    }
+    this.debouncedApplyChanges()
+  }
 
+  private workspaceConfig(): native.WorkspaceConfig {
     const configFrameworks = this.config.get('frameworks') as string[] | undefined
-    if (configFrameworks && configFrameworks.length > 0) {
-      this.frameworks = configFrameworks
+    // In-house component libraries scanned from source: [{ name, path, prefix? }]
+    const libraries = this.config.get('componentLibraries') as native.ComponentLibrary[] | undefined
+    return {
+      tabSize: this.tabSize,
+      locale: this.locale,
+      frameworks: configFrameworks,
+      componentPoster: (this.config.get('componentPoster') as string) || '.vue',
+      alias: this.prefix.alias,
+      componentPrefix: this.prefix.path,
+      componentLibraries: libraries,
+      // { style, dropVueExtension, dropScriptExtension, dropIndex }
+      importPaths: this.config.get('importPaths') as native.ImportPathOptions | undefined
     }
-    outputChannel.appendLine('final frameworks: ' + JSON.stringify(this.frameworks))
   }
 
-  public searchVueFiles() {
-    const poster = (this.config.get('componentPoster') as string) || '.vue'
-    this.vueFiles = native.searchFiles(
-      this.projectRootPath,
-      poster,
-      '',
-      true,
-      this.prefix.alias,
-      this.prefix.path
-    )
+  private syncVueFiles() {
+    this.vueFiles = this.session.files
     this.rebuildVueFileIndex()
   }
 
@@ -132,9 +199,17 @@ export default class ExplorerProvider {
 
   private watchFiles() {
     const watcher = workspace.createFileSystemWatcher('**/*.vue')
-    watcher.onDidCreate(() => this.debouncedSearchVueFiles())
-    watcher.onDidDelete(() => this.debouncedSearchVueFiles())
+    watcher.onDidCreate((uri) => this.fileChanged(uri, 'components'))
+    watcher.onDidDelete((uri) => this.fileChanged(uri, 'components'))
+    // Tag usage counts rank the completions
+    watcher.onDidChange((uri) => this.fileChanged(uri, 'saved'))
     this.context.subscriptions.push(watcher)
+
+    // Images, media and fonts offered in `src` and `url()`
+    const assetWatcher = workspace.createFileSystemWatcher('**/*.{png,jpg,jpeg,gif,svg,webp,avif,ico,bmp,mp4,webm,mp3,wav,woff,woff2,ttf,otf,eot}')
+    assetWatcher.onDidCreate((uri) => this.fileChanged(uri, 'assets'))
+    assetWatcher.onDidDelete((uri) => this.fileChanged(uri, 'assets'))
+    this.context.subscriptions.push(assetWatcher)
   }
 
   private openDocument(e: TextDocument) {
@@ -149,12 +224,18 @@ export default class ExplorerProvider {
     this.projectRootPath = this.getWorkspaceRoot(e.uri.path)
     this.projectRootPathReg = new RegExp('^' + this.projectRootPath.replace(/[\\/]/g, '[\\\\/]'))
     this.isTs = fs.existsSync(path.join(this.projectRootPath, 'tsconfig.json'))
-    native.invalidateFrameworkCache()
     this.detectFrameworks()
-    this.searchVueFiles()
     this.resetInit()
   }
 
+  private closeSession(root: string) {
+    const session = this.sessions.get(root)
+    if (session) {
+      session.dispose()
+      this.sessions.delete(root)
+    }
+  }
+
   private getWorkspaceRoot(documentUrl: string): string {
     let url = ''
     if (workspace.workspaceFolders?.length === 1) {
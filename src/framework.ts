@@ -2,7 +2,8 @@ import {
   CancellationToken, CompletionContext, CompletionItem, CompletionItemProvider, CompletionList,
   Position, ProviderResult, TextDocument, languages, workspace, Range, window,
   CompletionItemKind, SnippetString, HoverProvider, Hover, DefinitionProvider,
-  Definition, Uri, Location
+  Definition, Uri, Location, TextEdit, CompletionItemTag, MarkdownString,
+  Diagnostic, DiagnosticSeverity
 } from "vscode"
 import ExplorerProvider from './explorer'
 import * as native from './native'
@@ -36,7 +37,7 @@ export default class FrameworkProvider {
 
     const completionProvider = new FrameworkCompletionItemProvider(this)
     this.explorer.context.subscriptions.push(
-      languages.registerCompletionItemProvider(selector, completionProvider, '', ':', '<', '"', "'", '/', '@', '(', '>', '{')
+      languages.registerCompletionItemProvider(selector, completionProvider, '', ':', '<', '"', "'", '/', '@', '(', '>', '{', '.', '#', ',')
     )
 
     const hoverProvider = new FrameworkHoverProvider(this)
@@ -49,6 +50,25 @@ export default class FrameworkProvider {
       languages.registerDefinitionProvider(selector, definitionProvider)
     )
 
+    // Asset references pointing to no file, checked when a document is opened or saved
+    const assetDiagnostics = languages.createDiagnosticCollection('vue-helper')
+    const checkAssets = (document: TextDocument) => {
+      if (!['vue', 'html', 'css', 'scss', 'less'].includes(document.languageId)) return
+      const missing = this.explorer.session.missingAssets(document.getText(), document.uri.fsPath)
+      assetDiagnostics.set(document.uri, missing.map((asset) => new Diagnostic(
+        new Range(asset.line, asset.startChar, asset.line, asset.endChar),
+        `File not found: ${asset.path}`,
+        DiagnosticSeverity.Warning
+      )))
+    }
+    workspace.textDocuments.forEach(checkAssets)
+    this.explorer.context.subscriptions.push(
+      assetDiagnostics,
+      workspace.onDidOpenTextDocument(checkAssets),
+      workspace.onDidSaveTextDocument(checkAssets),
+      workspace.onDidCloseTextDocument((document) => assetDiagnostics.delete(document.uri))
+    )
+
     workspace.onDidChangeTextDocument((event: any) => {
       const editor = window.activeTextEditor
       if (!editor || event.document !== editor.document) return
@@ -73,8 +93,86 @@ export default class FrameworkProvider {
   }
 }
 
+// Tag and attribute lists are cut at this many items and completed again as the user types
+const MAX_COMPLETIONS = 100
+
+// Lists are requested one item longer than shown, so a list is only incomplete when it was cut
+function isCapped(lists: native.CompletionSuggestion[][]): boolean {
+  let capped = false
+  for (const list of lists) {
+    if (list.length > MAX_COMPLETIONS) {
+      list.length = MAX_COMPLETIONS
+      capped = true
+    }
+  }
+  return capped
+}
+
+const COMPLETION_KINDS: Record<native.CompletionKind, CompletionItemKind> = {
+  snippet: CompletionItemKind.Snippet,
+  property: CompletionItemKind.Property,
+  method: CompletionItemKind.Method,
+  value: CompletionItemKind.Value,
+  reference: CompletionItemKind.Reference,
+  folder: CompletionItemKind.Folder,
+  file: CompletionItemKind.File,
+  module: CompletionItemKind.Module
+}
+
+// Trigger characters only meaningful inside a tag (slot names and props) or after `this.$refs.name`
+const SCOPED_TRIGGERS = ['.', '#', ',']
+
+// Kinds inserting their text as is rather than as a snippet
+const PLAIN_KINDS: native.CompletionKind[] = ['value', 'folder', 'file', 'module']
+
+function toRange(range: native.CompletionRange) {
+  return {
+    inserting: new Range(range.line, range.startChar, range.line, range.insertEndChar),
+    replacing: new Range(range.line, range.startChar, range.line, range.replaceEndChar)
+  }
+}
+
+/** Editor item of a native suggestion, replacing `wordRange` unless the suggestion has its own range */
+function toCompletionItem(s: native.CompletionSuggestion, wordRange?: native.CompletionRange): CompletionItem {
+  const item = new CompletionItem(s.label, COMPLETION_KINDS[s.kind])
+  item.sortText = s.sortText
+  item.insertText = PLAIN_KINDS.includes(s.kind) ? s.insertText : new SnippetString(s.insertText)
+  item.detail = s.detail
+  item.documentation = s.markdown ? new MarkdownString(s.documentation) : s.documentation
+  const range = s.range ?? wordRange
+  if (range) item.range = toRange(range)
+  if (s.filterText !== undefined) item.filterText = s.filterText
+  if (s.commitCharacters.length > 0) item.commitCharacters = s.commitCharacters
+  if (s.deprecated) item.tags = [CompletionItemTag.Deprecated]
+  // Continue with the entries of the directory
+  if (s.kind === 'folder') item.command = { command: 'editor.action.triggerSuggest', title: 'Suggest' }
+  return item
+}
+
+function toTextEdit(edit: native.EditOperation): TextEdit {
+  return TextEdit.replace(new Range(edit.startLine, edit.startChar, edit.endLine, edit.endChar), edit.text)
+}
+
+// Event suggestion waiting for its handler edit, computed when the item is resolved
+interface PendingHandler {
+  session: native.Workspace
+  document: TextDocument
+  tag: string
+  event: string
+}
+
+// Project component waiting for the edits importing it, computed when the item is resolved
+interface PendingImport {
+  session: native.Workspace
+  document: TextDocument
+  tag: string
+  specifier: string
+}
+
 class FrameworkCompletionItemProvider implements CompletionItemProvider {
   private frameworkProvider: FrameworkProvider
+  private pendingHandlers: WeakMap<CompletionItem, PendingHandler> = new WeakMap()
+  private pendingImports: WeakMap<CompletionItem, PendingImport> = new WeakMap()
 
   constructor(frameworkProvider: FrameworkProvider) {
     this.frameworkProvider = frameworkProvider
@@ -91,9 +189,11 @@ class FrameworkCompletionItemProvider implements CompletionItemProvider {
 
     const lineText = document.lineAt(position.line).text
     const textBeforeCursor = lineText.substring(0, position.character)
+    // The tag or attribute name around the cursor, and the part typed so far
+    const wordRange = native.getCompletionRange(lineText, position.line, position.character)
+    const typedWord = lineText.substring(wordRange.startChar, wordRange.insertEndChar)
     const explorer = this.frameworkProvider.explorer
-    const frameworks = explorer.frameworks
-    const tabSize = explorer.tabSize
+    const session = explorer.session
 
     const range = new Range(new Position(0, 0), position)
     const fullTextBefore = document.getText(range)
@@ -101,41 +201,43 @@ class FrameworkCompletionItemProvider implements CompletionItemProvider {
     const lines = document.getText().split('\n')
     const notInTemplate = native.checkNotInTemplate(lines, position.line)
 
+    // Paths inside `from '...'`, `import('...')` and `require('...')`
+    const pathSuggestions = session.modulePathCompletions(lineText, position.line, position.character, document.uri.fsPath)
+    if (pathSuggestions) {
+      return pathSuggestions.map((s) => toCompletionItem(s))
+    }
+    // Assets in `src="..."` and `url(...)`
+    const assetSuggestions = session.assetPathCompletions(lineText, position.line, position.character, document.uri.fsPath)
+    if (assetSuggestions) {
+      return assetSuggestions.map((s) => toCompletionItem(s))
+    }
+
     if (native.isImportLine(textBeforeCursor)) {
-      const suggestions = native.getImportSuggestions(
-        textBeforeCursor,
-        explorer.vueFiles,
-        document.uri.fsPath,
-        explorer.projectRootPath
-      )
-      return suggestions.map((s: any) => {
-        const item = new CompletionItem(s.label, CompletionItemKind.Reference)
-        item.sortText = s.sortText
-        item.insertText = new SnippetString(s.insertText)
-        item.detail = s.detail
-        item.documentation = s.documentation
-        return item
-      })
+      const suggestions = session.importSuggestions(textBeforeCursor, document.uri.fsPath)
+      return suggestions.map((s) => toCompletionItem(s))
     }
 
+    const scopedTrigger = SCOPED_TRIGGERS.includes(context.triggerCharacter ?? '')
     if (!notInTemplate) {
       const preTag = native.matchPreTag(fullTextBefore)
       if (preTag) {
+        // Slot names after `#` / `v-slot:`, slot props inside `#default="{ }"`
+        const slotSuggestions = session.slotCompletions(fullTextBefore)
+        if (slotSuggestions.length > 0) {
+          return slotSuggestions.map((s) => toCompletionItem(s))
+        }
+
         const preAttr = native.matchPreAttr(fullTextBefore)
         if (preAttr) {
-          const attrSuggestions = native.getAttrValueCompletions(preTag.text, preAttr, frameworks, tabSize)
-          return attrSuggestions.map((s: any) => {
-            const item = new CompletionItem(s.label, CompletionItemKind.Value)
-            item.sortText = s.sortText
-            item.insertText = s.insertText
-            item.detail = s.detail
-            return item
-          })
+          const attrSuggestions = session.attrValueCompletions(preTag.text, preAttr)
+          return attrSuggestions.map((s) => toCompletionItem(s))
         }
+        if (scopedTrigger) return []
 
-        const triggerChar = context.triggerCharacter || textBeforeCursor.slice(-1)
+        const triggerChar = context.triggerCharacter || textBeforeCursor.charAt(textBeforeCursor.length - typedWord.length - 1)
         if (triggerChar === ' ' || triggerChar === ':' || triggerChar === '@') {
-          const attrSuggestions = native.getAttrCompletions(preTag.text, frameworks, tabSize, triggerChar)
+          const attrSuggestions = session.attrCompletions(preTag.text, triggerChar, typedWord, MAX_COMPLETIONS + 1)
+          const isIncomplete = isCapped([attrSuggestions])
 
           const tag = preTag.text
           const normalizedTag = tag.toLowerCase().replace(/-/g, '')
@@ -155,39 +257,62 @@ class FrameworkCompletionItemProvider implements CompletionItemProvider {
             }
           }
 
-          return attrSuggestions.map((s: any) => {
-            const kind = s.kind === 'method' ? CompletionItemKind.Method : CompletionItemKind.Property
-            const item = new CompletionItem(s.label, kind)
-            item.sortText = s.sortText
-            item.insertText = s.insertText
-            item.detail = s.detail
-            item.documentation = s.documentation
+          const items = attrSuggestions.map((s) => {
+            const item = toCompletionItem(s, wordRange)
+            if (s.kind === 'method') {
+              this.pendingHandlers.set(item, { session, document, tag, event: s.label })
+            }
             return item
           })
+          return new CompletionList(items, isIncomplete)
         }
       }
 
-      const tagSuggestions = native.getTagCompletions(frameworks, tabSize, true)
-      const elementLabels = native.getElementTagLabels(frameworks, tabSize, explorer.name)
-      return [...tagSuggestions, ...elementLabels].map((s: any) => {
-        const item = new CompletionItem(s.label, CompletionItemKind.Snippet)
-        item.sortText = s.sortText
-        item.insertText = new SnippetString(s.insertText)
-        item.detail = s.detail
-        item.documentation = s.documentation
-        return item
-      })
+      if (scopedTrigger) return []
+      const tagSuggestions = session.tagCompletions(true, fullTextBefore, typedWord, MAX_COMPLETIONS + 1)
+      const elementLabels = session.elementTagLabels(explorer.name, fullTextBefore, typedWord, MAX_COMPLETIONS + 1)
+      // Project components, imported and registered in the script when completed
+      const componentTags = session.componentTagCompletions(document.uri.fsPath, typedWord, MAX_COMPLETIONS + 1)
+      const autoImport = config.get('autoImport') as boolean ?? true
+      const isIncomplete = isCapped([tagSuggestions, elementLabels, componentTags])
+      const items = [...tagSuggestions, ...elementLabels].map((s) => toCompletionItem(s, wordRange))
+      for (const s of componentTags) {
+        const item = toCompletionItem(s, wordRange)
+        if (autoImport) this.pendingImports.set(item, { session, document, tag: s.label, specifier: s.detail })
+        items.push(item)
+      }
+      return new CompletionList(items, isIncomplete)
     }
 
-    const jsSuggestions = native.getJsTagCompletions(frameworks, tabSize, true)
-    return jsSuggestions.map((s: any) => {
-      const item = new CompletionItem(s.label, CompletionItemKind.Snippet)
-      item.sortText = s.sortText
-      item.insertText = new SnippetString(s.insertText)
-      item.detail = s.detail
-      item.documentation = s.documentation
-      return item
-    })
+    // `this.$refs.name.` offers the methods of the component with `ref="name"`
+    const refSuggestions = session.refMethodCompletions(document.getText(), textBeforeCursor)
+    if (refSuggestions.length > 0) {
+      return refSuggestions.map((s) => toCompletionItem(s))
+    }
+    if (scopedTrigger) return []
+
+    const jsSuggestions = session.jsTagCompletions(true)
+    return jsSuggestions.map((s) => toCompletionItem(s))
+  }
+
+  // Add an empty handler with the event payload as parameters to the script, or import and
+  // register a project component, only for the item the user is about to pick
+  resolveCompletionItem(item: CompletionItem, _token: CancellationToken): ProviderResult<CompletionItem> {
+    const pending = this.pendingHandlers.get(item)
+    if (pending) {
+      this.pendingHandlers.delete(item)
+      const edit = pending.session.eventHandlerEdit(pending.document.getText(), pending.tag, pending.event)
+      if (edit) {
+        item.additionalTextEdits = [toTextEdit(edit)]
+      }
+    }
+    const pendingImport = this.pendingImports.get(item)
+    if (pendingImport) {
+      this.pendingImports.delete(item)
+      const edits = pendingImport.session.componentImportEdits(pendingImport.document.getText(), pendingImport.tag, pendingImport.specifier)
+      if (edits.length > 0) item.additionalTextEdits = edits.map(toTextEdit)
+    }
+    return item
   }
 }
 
@@ -210,7 +335,9 @@ class FrameworkHoverProvider implements HoverProvider {
 
     if (!word) return null
 
-    const hoverText = native.provideHover(word, explorer.frameworks, explorer.tabSize)
+    // Attributes and events are resolved against the enclosing tag
+    const textBefore = document.getText(new Range(new Position(0, 0), position))
+    const hoverText = explorer.session.hoverAt(textBefore, word)
     if (!hoverText) return null
 
     return new Hover(hoverText)
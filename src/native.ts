@@ -10,16 +10,43 @@ try {
 
 export interface VueFile {
   name: string
+  // Import path with the alias, `@/components/UserList.vue`
+  path: string
+  // Path relative to the project root, `src/components/UserList.vue`
+  relativePath: string
+}
+
+export type CompletionKind = 'snippet' | 'property' | 'method' | 'value' | 'reference' | 'folder' | 'file' | 'module'
+
+/** Text a completion replaces on one line: up to the cursor on insert, to the end of the word on replace */
+export interface CompletionRange {
+  line: number
+  startChar: number
+  insertEndChar: number
+  replaceEndChar: number
+}
+
+/** An asset reference pointing to no file */
+export interface MissingAsset {
+  line: number
+  startChar: number
+  endChar: number
   path: string
 }
 
 export interface CompletionSuggestion {
   label: string
   sortText: string
+  /** Snippet syntax, except for the `value` kind */
   insertText: string
-  kind: string
+  kind: CompletionKind
   detail: string
   documentation: string
+  markdown: boolean
+  deprecated: boolean
+  range?: CompletionRange
+  filterText?: string
+  commitCharacters: string[]
 }
 
 export interface TagMatch {
@@ -54,17 +81,90 @@ export interface EnhanceResult {
   actionType: string
 }
 
+export interface DetectedFramework {
+  name: string
+  package: string
+  range: string
+  version?: string
+  spec: string
+}
+
 export interface DefinitionLocation {
   filePath: string
   line: number
   character: number
 }
 
+export interface ComponentLibrary {
+  name: string
+  path: string
+  prefix?: string
+}
+
+export interface ImportPathOptions {
+  alias?: string
+  aliasPath?: string
+  // 'relative', 'alias', or 'auto': relative inside the edited file's directory, the alias elsewhere
+  style?: string
+  dropVueExtension?: boolean
+  dropScriptExtension?: boolean
+  dropIndex?: boolean
+}
+
+export interface WorkspaceConfig {
+  tabSize?: string
+  locale?: string
+  frameworks?: string[]
+  componentPoster?: string
+  alias?: string
+  componentPrefix?: string
+  componentLibraries?: ComponentLibrary[]
+  importPaths?: ImportPathOptions
+}
+
+// Session for one workspace root: owns its frameworks, settings, file index and framework data
+export interface Workspace {
+  readonly root: string
+  readonly frameworks: string[]
+  readonly files: VueFile[]
+  // Messages of the last refresh, for the output channel
+  readonly messages: string[]
+  setConfig(config: WorkspaceConfig): string[]
+  refresh(): string[]
+  reindexFiles(): void
+  reindexAssets(): void
+  updateFile(path: string): void
+  dispose(): void
+  tagCompletions(useVueSnippets: boolean, textBefore?: string, prefix?: string, maxCount?: number): CompletionSuggestion[]
+  jsTagCompletions(useVueSnippets: boolean): CompletionSuggestion[]
+  attrCompletions(tag: string, attrType: string, prefix?: string, maxCount?: number): CompletionSuggestion[]
+  attrValueCompletions(tag: string, attr: string): CompletionSuggestion[]
+  elementTagLabels(extensionName: string, textBefore?: string, prefix?: string, maxCount?: number): CompletionSuggestion[]
+  slotCompletions(textBefore: string): CompletionSuggestion[]
+  eventHandlerEdit(content: string, tag: string, event: string): EditOperation | null
+  refMethodCompletions(content: string, textBefore: string): CompletionSuggestion[]
+  hoverAt(textBefore: string, word: string): string | null
+  importSuggestions(searchText: string, documentPath: string): CompletionSuggestion[]
+  componentTagCompletions(documentPath: string, prefix?: string, maxCount?: number): CompletionSuggestion[]
+  componentImportEdits(content: string, tag: string, specifier: string): EditOperation[]
+  modulePathCompletions(lineText: string, line: number, character: number, documentPath: string): CompletionSuggestion[] | null
+  assetPathCompletions(lineText: string, line: number, character: number, documentPath: string): CompletionSuggestion[] | null
+  missingAssets(content: string, documentPath: string): MissingAsset[]
+}
+
+export function openWorkspace(root: string, config?: WorkspaceConfig): Workspace {
+  return new nativeModule.Workspace(root, config)
+}
+
 // Framework init
 export function initFrameworks(packageJsonContent: string): string[] {
   return nativeModule.initFrameworks(packageJsonContent)
 }
 
+export function detectFrameworks(packageJsonContent: string, projectRoot?: string): DetectedFramework[] {
+  return nativeModule.detectFrameworks(packageJsonContent, projectRoot)
+}
+
 // File search
 export function searchFiles(
   rootPath: string, poster: string, searchName: string,
@@ -74,37 +174,57 @@ export function searchFiles(
 }
 
 // Completions
-export function getTagCompletions(frameworks: string[], tabSize: string, useVueSnippets: boolean): CompletionSuggestion[] {
-  return nativeModule.getTagCompletions(frameworks, tabSize, useVueSnippets)
+export function getCompletionRange(lineText: string, line: number, character: number): CompletionRange {
+  return nativeModule.getCompletionRange(lineText, line, character)
+}
+
+export function getTagCompletions(frameworks: string[], tabSize: string, useVueSnippets: boolean, locale?: string, textBefore?: string, prefix?: string, maxCount?: number): CompletionSuggestion[] {
+  return nativeModule.getTagCompletions(frameworks, tabSize, useVueSnippets, locale, textBefore, prefix, maxCount)
 }
 
-export function getJsTagCompletions(frameworks: string[], tabSize: string, useVueSnippets: boolean): CompletionSuggestion[] {
-  return nativeModule.getJsTagCompletions(frameworks, tabSize, useVueSnippets)
+export function getJsTagCompletions(frameworks: string[], tabSize: string, useVueSnippets: boolean, locale?: string): CompletionSuggestion[] {
+  return nativeModule.getJsTagCompletions(frameworks, tabSize, useVueSnippets, locale)
 }
 
-export function getAttrCompletions(tag: string, frameworks: string[], tabSize: string, attrType: string): CompletionSuggestion[] {
-  return nativeModule.getAttrCompletions(tag, frameworks, tabSize, attrType)
+export function getAttrCompletions(tag: string, frameworks: string[], attrType: string, locale?: string, prefix?: string, maxCount?: number): CompletionSuggestion[] {
+  return nativeModule.getAttrCompletions(tag, frameworks, attrType, locale, prefix, maxCount)
 }
 
-export function getAttrValueCompletions(tag: string, attr: string, frameworks: string[], tabSize: string): CompletionSuggestion[] {
-  return nativeModule.getAttrValueCompletions(tag, attr, frameworks, tabSize)
+export function getAttrValueCompletions(tag: string, attr: string, frameworks: string[], locale?: string): CompletionSuggestion[] {
+  return nativeModule.getAttrValueCompletions(tag, attr, frameworks, locale)
 }
 
-export function getElementTagLabels(frameworks: string[], tabSize: string, extensionName: string): CompletionSuggestion[] {
-  return nativeModule.getElementTagLabels(frameworks, tabSize, extensionName)
+export function getElementTagLabels(frameworks: string[], extensionName: string, locale?: string, textBefore?: string, prefix?: string, maxCount?: number): CompletionSuggestion[] {
+  return nativeModule.getElementTagLabels(frameworks, extensionName, locale, textBefore, prefix, maxCount)
+}
+
+export function getSlotCompletions(textBefore: string, frameworks: string[], locale?: string): CompletionSuggestion[] {
+  return nativeModule.getSlotCompletions(textBefore, frameworks, locale)
+}
+
+export function getEventHandlerEdit(content: string, tag: string, event: string, frameworks: string[], tabSize: string, locale?: string): EditOperation | null {
+  return nativeModule.getEventHandlerEdit(content, tag, event, frameworks, tabSize, locale)
+}
+
+export function getRefMethodCompletions(content: string, textBefore: string, frameworks: string[], locale?: string): CompletionSuggestion[] {
+  return nativeModule.getRefMethodCompletions(content, textBefore, frameworks, locale)
 }
 
 export function extractVueProps(fileContent: string): CompletionSuggestion[] {
   return nativeModule.extractVueProps(fileContent)
 }
 
-export function getImportSuggestions(searchText: string, vueFiles: VueFile[], documentPath: string, projectRoot: string): CompletionSuggestion[] {
-  return nativeModule.getImportSuggestions(searchText, vueFiles, documentPath, projectRoot)
+export function getImportSuggestions(searchText: string, vueFiles: VueFile[], documentPath: string, projectRoot: string, options?: ImportPathOptions): CompletionSuggestion[] {
+  return nativeModule.getImportSuggestions(searchText, vueFiles, documentPath, projectRoot, options)
 }
 
 // Hover
-export function provideHover(word: string, frameworks: string[], tabSize: string): string | null {
-  return nativeModule.provideHover(word, frameworks, tabSize)
+export function provideHover(word: string, frameworks: string[], locale?: string): string | null {
+  return nativeModule.provideHover(word, frameworks, locale)
+}
+
+export function provideHoverAt(textBefore: string, word: string, frameworks: string[], locale?: string): string | null {
+  return nativeModule.provideHoverAt(textBefore, word, frameworks, locale)
 }
 
 // Tag matching